@@ -0,0 +1,79 @@
+//! Lists the example files of every day for the tests generated by `advent_of_code::example_tests!`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo::rerun-if-changed=src/bin");
+
+    let out_dir = Path::new(&env::var("OUT_DIR").unwrap()).join("examples");
+    let bin_dir = Path::new("src").join("bin");
+    fs::create_dir_all(out_dir.join(&bin_dir)).unwrap();
+
+    let bins = fs::read_dir(&bin_dir).into_iter().flatten().flatten();
+
+    for bin in bins {
+        let name = bin.file_name().to_string_lossy().to_string();
+        let Some(stem) = name.strip_suffix(".rs") else {
+            continue;
+        };
+
+        // NOTE: the tests are keyed by the path of the solution, see `file!()` in `example_tests!`.
+        let tests: String = puzzle_of(stem)
+            .map(|(year, day)| {
+                Path::new("data")
+                    .join(year)
+                    .join(format!("day_{day}"))
+                    .join("examples")
+            })
+            .filter(|examples_dir| examples_dir.is_dir())
+            .map(|examples_dir| {
+                // NOTE: only the scanned directories are watched, the rest of `data` is written
+                // by most commands. A missing directory would rerun the script on every build;
+                // `cargo scaffold` creates it together with the solution, which is watched.
+                println!("cargo::rerun-if-changed={}", examples_dir.display());
+
+                let mut files: Vec<String> = fs::read_dir(&examples_dir)
+                    .into_iter()
+                    .flatten()
+                    .flatten()
+                    .map(|entry| entry.file_name().to_string_lossy().to_string())
+                    .filter(|name| name.ends_with(".txt"))
+                    .collect();
+                files.sort();
+                files
+            })
+            .unwrap_or_default()
+            .iter()
+            .map(|file| {
+                format!(
+                    "::advent_of_code::example_test!({}, {file:?});\n",
+                    test_name(file)
+                )
+            })
+            .collect();
+
+        fs::write(out_dir.join(&bin_dir).join(&name), &tests).unwrap();
+    }
+}
+
+/// Splits the name of a solution like `2025_09` into its year and day.
+fn puzzle_of(stem: &str) -> Option<(&str, &str)> {
+    let (year, day) = stem.split_once('_')?;
+    let is_number = |x: &str| !x.is_empty() && x.chars().all(|c| c.is_ascii_digit());
+
+    (is_number(year) && is_number(day)).then_some((year, day))
+}
+
+/// Turns a file name like `example-1.txt` into a valid test name like `example_1`.
+fn test_name(file: &str) -> String {
+    let stem = file.trim_end_matches(".txt");
+    let name: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("example_{name}")
+    } else {
+        name
+    }
+}
@@ -1,3 +1,5 @@
+pub mod ocr;
+
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::fmt::Debug;
@@ -386,6 +388,21 @@ impl<T: Display> Matrix<T> {
     }
 }
 
+/// Renders the matrix row by row, so it can be returned as a grid-shaped answer.
+impl<T: Display> Display for Matrix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.size.y {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for x in 0..self.size.x {
+                write!(f, "{}", self[y][x])?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<T> std::ops::Index<usize> for Matrix<T> {
     type Output = [T];
 
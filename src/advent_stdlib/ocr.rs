@@ -0,0 +1,247 @@
+/// Recognition of the capital letters that some puzzles draw on a grid instead of
+/// printing an answer. Both AoC fonts are supported: the small one is 6 rows tall,
+/// the large one 10 rows tall. Letters are told apart by the empty columns between them.
+use crate::advent_stdlib::Matrix;
+
+/// Letters of the 6 rows tall font.
+const SMALL_FONT: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Letters of the 10 rows tall font.
+const LARGE_FONT: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+/// Reads the letters drawn in `drawing`, where `#` or `█` are lit and `.` or spaces are not.
+/// Returns `None` if the drawing contains anything else or a letter is not recognized.
+///
+/// # Examples
+/// ```ignore
+/// use advent_of_code::advent_stdlib::ocr;
+///
+/// let drawing = "#..#.###.\n#..#..#..\n####..#..\n#..#..#..\n#..#..#..\n#..#.###.";
+/// assert_eq!(ocr::recognize(drawing), Some("HI".to_string()));
+/// ```
+#[must_use]
+pub fn recognize(drawing: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = drawing
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| match c {
+                    '#' | '█' => Some(true),
+                    '.' | ' ' => Some(false),
+                    _ => None,
+                })
+                .collect()
+        })
+        .collect::<Option<_>>()?;
+
+    recognize_rows(&rows)
+}
+
+/// Reads the letters drawn in `matrix`, see [`recognize`].
+#[must_use]
+pub fn recognize_matrix(matrix: &Matrix<bool>) -> Option<String> {
+    let rows: Vec<Vec<bool>> = (0..matrix.size.y).map(|y| matrix[y].to_vec()).collect();
+    recognize_rows(&rows)
+}
+
+fn recognize_rows(rows: &[Vec<bool>]) -> Option<String> {
+    let lit_rows: Vec<&Vec<bool>> = {
+        let first = rows.iter().position(|row| row.contains(&true))?;
+        let last = rows.iter().rposition(|row| row.contains(&true))?;
+        rows[first..=last].iter().collect()
+    };
+
+    let font: &[(char, &str)] = match lit_rows.len() {
+        6 => &SMALL_FONT,
+        10 => &LARGE_FONT,
+        _ => return None,
+    };
+
+    let width = lit_rows.iter().map(|row| row.len()).max()?;
+    let is_lit = |x: usize, y: usize| lit_rows[y].get(x).copied().unwrap_or(false);
+    let is_empty_column = |x: usize| (0..lit_rows.len()).all(|y| !is_lit(x, y));
+
+    let mut text = String::new();
+    let mut x = 0;
+
+    while x < width {
+        if is_empty_column(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && !is_empty_column(x) {
+            x += 1;
+        }
+
+        let glyph: Vec<String> = (0..lit_rows.len())
+            .map(|y| {
+                (start..x)
+                    .map(|x| if is_lit(x, y) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let glyph = glyph.join("\n");
+
+        let (letter, _) = font.iter().find(|(_, pattern)| *pattern == glyph)?;
+        text.push(*letter);
+    }
+
+    Some(text)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{LARGE_FONT, SMALL_FONT, recognize, recognize_matrix};
+    use crate::advent_stdlib::{Matrix, Size};
+
+    /// Draws `text` with one empty column between letters, the way puzzles do.
+    fn draw(font: &[(char, &str)], text: &str) -> String {
+        let glyphs: Vec<Vec<&str>> = text
+            .chars()
+            .map(|c| {
+                let (_, pattern) = font.iter().find(|(letter, _)| *letter == c).unwrap();
+                pattern.lines().collect()
+            })
+            .collect();
+
+        (0..glyphs[0].len())
+            .map(|y| {
+                glyphs
+                    .iter()
+                    .map(|glyph| format!("{}.", glyph[y]))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn recognizes_small_font() {
+        let text: String = SMALL_FONT.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(recognize(&draw(&SMALL_FONT, &text)), Some(text));
+    }
+
+    #[test]
+    fn recognizes_large_font() {
+        let text: String = LARGE_FONT.iter().map(|(letter, _)| letter).collect();
+        let drawing = draw(&LARGE_FONT, &text).replace('#', "█").replace('.', " ");
+        assert_eq!(recognize(&drawing), Some(text));
+    }
+
+    #[test]
+    fn ignores_empty_rows_and_columns() {
+        let drawing = format!("\n......\n{}\n", draw(&SMALL_FONT, "EZ"));
+        let drawing = drawing.replace('\n', "\n..");
+        assert_eq!(recognize(&drawing), Some("EZ".into()));
+    }
+
+    #[test]
+    fn recognizes_documented_drawing() {
+        let drawing = "#..#.###.\n#..#..#..\n####..#..\n#..#..#..\n#..#..#..\n#..#.###.";
+        assert_eq!(recognize(drawing), Some("HI".into()));
+    }
+
+    #[test]
+    fn rejects_unknown_drawings() {
+        assert_eq!(recognize("#.#\n.#.\n#.#"), None);
+        assert_eq!(recognize("1234"), None);
+        assert_eq!(recognize(""), None);
+    }
+
+    #[test]
+    fn recognizes_matrices() {
+        let drawing = draw(&SMALL_FONT, "HI");
+        let width = drawing.lines().next().unwrap().len();
+        let data: Vec<bool> = drawing
+            .lines()
+            .flat_map(|line| line.chars().map(|c| c == '#'))
+            .collect();
+        let matrix = Matrix {
+            size: Size { x: width, y: 6 },
+            data,
+        };
+        assert_eq!(recognize_matrix(&matrix), Some("HI".into()));
+    }
+}
@@ -1,19 +1,43 @@
+use advent_of_code::template::variants::Rng;
+
 advent_of_code::solution!(2);
+advent_of_code::variants!(
+    1 => [part_one, part_one_slow],
+    2 => [part_two, part_two_slow];
+    generator = random_input
+);
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let ranges_str: Vec<&str> = input.trim_end().split(',').collect();
-    let ranges = ranges_str.iter().map(|s| {
-        let mut split = s.split('-');
-        Range {
-            start_index: split.next().unwrap().parse().unwrap(),
-            end_index: split.next().unwrap().parse().unwrap(),
-        }
-    });
-
-    Some(ranges.fold(0, |acc, x| acc + count_invalid(x, test_invalid_part1_fast)))
+    Some(sum_invalid(input, test_invalid_part1_fast))
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
+    Some(sum_invalid(input, test_invalid_part2_fast))
+}
+
+pub fn part_one_slow(input: &str) -> Option<u64> {
+    Some(sum_invalid(input, test_invalid_part1))
+}
+
+pub fn part_two_slow(input: &str) -> Option<u64> {
+    Some(sum_invalid(input, test_invalid_part2))
+}
+
+/// Random comma separated ranges of up to 10 digits for comparing the variants.
+fn random_input(rng: &mut Rng) -> String {
+    let ranges: Vec<String> = (0..rng.range(1, 5))
+        .map(|_| {
+            let digits = rng.range(1, 10) as u32;
+            let start = rng.range(1, 10_u64.pow(digits) - 1);
+            let end = (start + rng.range(0, 2000)).min(9_999_999_999);
+            format!("{start}-{end}")
+        })
+        .collect();
+
+    ranges.join(",")
+}
+
+fn sum_invalid(input: &str, test_invalid: fn(u64) -> bool) -> u64 {
     let ranges_str: Vec<&str> = input.trim_end().split(',').collect();
     let ranges = ranges_str.iter().map(|s| {
         let mut split = s.split('-');
@@ -23,7 +47,7 @@ pub fn part_two(input: &str) -> Option<u64> {
         }
     });
 
-    Some(ranges.fold(0, |acc, x| acc + count_invalid(x, test_invalid_part2_fast)))
+    ranges.fold(0, |acc, x| acc + count_invalid(x, test_invalid))
 }
 
 fn count_invalid(range: Range, test_invalid: fn(u64) -> bool) -> u64 {
@@ -37,7 +61,6 @@ fn count_invalid(range: Range, test_invalid: fn(u64) -> bool) -> u64 {
     res
 }
 
-#[allow(dead_code)]
 fn test_invalid_part1(input: u64) -> bool {
     let digits = get_digits(input);
     if !digits.len().is_multiple_of(2) {
@@ -71,7 +94,6 @@ fn test_invalid_part1_fast(input: u64) -> bool {
     }
 }
 
-#[allow(dead_code)]
 fn test_invalid_part2(input: u64) -> bool {
     let digits = get_digits(input);
 
@@ -345,24 +367,4 @@ mod tests {
         let result = test_invalid_part2_fast(1188511880);
         assert!(!result);
     }
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(1227775554));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(4174379265));
-    }
 }
@@ -1,4 +1,11 @@
+use advent_of_code::template::variants::Rng;
+
 advent_of_code::solution!(3);
+advent_of_code::variants!(
+    1 => [part_one, part_one_iterative],
+    2 => [part_two, part_two_recursive];
+    generator = random_input
+);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let lines: Vec<Vec<u32>> = input
@@ -13,17 +20,40 @@ pub fn part_one(input: &str) -> Option<u64> {
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
+    Some(total_joltage(input, |l| find_max_for_line_iterative(l, 12)))
+}
+
+pub fn part_one_iterative(input: &str) -> Option<u64> {
+    Some(total_joltage(input, |l| find_max_for_line_iterative(l, 2)))
+}
+
+pub fn part_two_recursive(input: &str) -> Option<u64> {
+    Some(total_joltage(input, |l| {
+        find_max_for_line_recursive(l, 0, 12)
+    }))
+}
+
+/// Random banks of 12 to 100 batteries for comparing the variants.
+fn random_input(rng: &mut Rng) -> String {
+    let lines: Vec<String> = (0..rng.range(1, 10))
+        .map(|_| {
+            (0..rng.range(12, 100))
+                .map(|_| char::from_digit(rng.range(1, 9) as u32, 10).unwrap())
+                .collect()
+        })
+        .collect();
+
+    lines.join("\n")
+}
+
+fn total_joltage(input: &str, max_for_line: impl Fn(&[u64]) -> u64) -> u64 {
     let lines: Vec<Vec<u64>> = input
         .trim_end()
         .lines()
         .map(|l| l.chars().map(|c| c.to_digit(10).unwrap() as u64).collect())
         .collect();
 
-    let res = lines
-        .iter()
-        .fold(0, |acc, l| acc + find_max_for_line_iterative(l, 12));
-
-    Some(res)
+    lines.iter().fold(0, |acc, l| acc + max_for_line(l))
 }
 
 fn find_max_for_line(line: &[u32]) -> u32 {
@@ -49,7 +79,6 @@ fn find_max_for_line(line: &[u32]) -> u32 {
     first_digit * 10 + second_digit
 }
 
-#[allow(dead_code)]
 fn find_max_for_line_recursive(line: &[u64], start_index: usize, count: usize) -> u64 {
     // find biggest digit - and biggest digit after it
 
@@ -182,24 +211,4 @@ mod tests {
             find_max_for_line_iterative(&[9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1], 12);
         assert_eq!(result, 987654321111);
     }
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(357));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(3121910778619));
-    }
 }
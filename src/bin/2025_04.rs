@@ -1,4 +1,5 @@
 advent_of_code::solution!(4);
+advent_of_code::variants!(2 => [part_two_queue, part_two_bruteforce]);
 
 use advent_of_code::advent_stdlib::{Index, Matrix};
 use std::{collections::VecDeque, fmt::Display};
@@ -71,7 +72,6 @@ fn part_two_queue(input: &str) -> Option<u64> {
     Some(removed)
 }
 
-#[allow(dead_code)]
 fn part_two_bruteforce(input: &str) -> Option<u64> {
     let mut map = Matrix::<MapCell>::from_char_input(input.trim_end());
 
@@ -245,38 +245,3 @@ impl Display for MapCell {
         write!(f, "{}", ch)
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(43));
-    }
-
-    #[test]
-    fn test_part_two_bruteforce() {
-        let result = part_two_bruteforce(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(43));
-    }
-}
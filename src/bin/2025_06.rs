@@ -193,29 +193,9 @@ mod tests {
     fn test_part_one() {
         let result = part_one_3_args(&advent_of_code::template::read_file_input(
             "examples",
-            DAY,
+            PUZZLE,
             "example_1.txt",
         ));
         assert_eq!(result, Some(4277556));
     }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(3263827));
-    }
-
-    #[test]
-    fn test_part_two_2() {
-        let result = part_two(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_2.txt",
-        ));
-        assert_eq!(result, Some(9580 + 7238619 + 6325));
-    }
 }
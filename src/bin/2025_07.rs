@@ -3,6 +3,7 @@ use std::fmt::Display;
 use advent_of_code::advent_stdlib::Matrix;
 
 advent_of_code::solution!(7);
+advent_of_code::variants!(2 => [part_two_single_traverse, part_two_double_traverse]);
 
 pub fn part_one(input: &str) -> Option<u64> {
     // Just go from top to bottom, simulating beams.
@@ -213,38 +214,3 @@ impl Display for MapCell {
         write!(f, "{}", ch)
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(21));
-    }
-
-    #[test]
-    fn test_part_two_double_traverse() {
-        let result = part_two_double_traverse(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(40));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(40));
-    }
-}
@@ -1,12 +1,10 @@
 use std::{collections::HashMap, fmt::Display};
 
-advent_of_code::solution!(8);
+use advent_of_code::template::examples;
 
-pub fn part_one(input: &str) -> Option<u64> {
-    part_one_for_iteration(input, 1000)
-}
+advent_of_code::solution!(8, parse = parse);
 
-fn part_one_for_iteration(input: &str, iteration_count: usize) -> Option<u64> {
+pub fn parse(input: &str) -> Playground {
     let boxes: Vec<JunctionBox> = input
         .trim()
         .lines()
@@ -32,6 +30,17 @@ fn part_one_for_iteration(input: &str, iteration_count: usize) -> Option<u64> {
     //     println!("[{}:{}] - [{}:{}]", e.0, boxes[e.0], e.1, boxes[e.1]);
     // }
 
+    Playground { boxes, edges }
+}
+
+pub fn part_one(input: &Playground) -> Option<u64> {
+    let connections = examples::param("connections").unwrap_or(1000);
+    part_one_for_iteration(input, connections)
+}
+
+fn part_one_for_iteration(input: &Playground, iteration_count: usize) -> Option<u64> {
+    let Playground { boxes, edges } = input;
+
     let mut dsu = UnionFind::new(boxes.len());
     let mut chosen_edges = Vec::new();
 
@@ -61,39 +70,20 @@ fn part_one_for_iteration(input: &str, iteration_count: usize) -> Option<u64> {
     Some((result[0] * result[1] * result[2]) as u64)
 }
 
-fn part_two(input: &str) -> Option<u64> {
+fn part_two(input: &Playground) -> Option<u64> {
     // same as part one, but waiting until elements-1 merges in Dsu
 
-    let lines: Vec<&str> = input.trim_end().lines().collect();
-    let boxes: Vec<JunctionBox> = lines
-        .iter()
-        .enumerate()
-        .map(|(i, line)| JunctionBox::from(line, i))
-        .collect();
+    let Playground { boxes, edges } = input;
 
     // Number of merges = number of elements - 1
-    let expected_merges_count = lines.len() - 1;
-
-    let mut edges: Vec<(usize, usize, f32)> = vec![];
-    for i in 0..boxes.len() {
-        for j in i + 1..boxes.len() {
-            edges.push((i, j, boxes[i].distance_to(boxes[j])));
-        }
-    }
-
-    // Sort by distance ascending
-    edges.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap());
-
-    // for e in &edges {
-    //     println!("[{}:{}] - [{}:{}]", e.0, boxes[e.0], e.1, boxes[e.1]);
-    // }
+    let expected_merges_count = boxes.len() - 1;
 
     let mut dsu = UnionFind::new(boxes.len());
 
     let mut iterations = 0;
     let mut chosen_edges = Vec::new();
 
-    for &(a, b, dist) in &edges {
+    for &(a, b, dist) in edges {
         //println!("===> [{} - {}]", a, b);
         if dsu.find(a) != dsu.find(b) {
             dsu.union(a, b);
@@ -137,6 +127,11 @@ fn three_largest_values(map: &HashMap<usize, usize>) -> Option<[usize; 3]> {
     Some([first, second, third])
 }
 
+pub struct Playground {
+    boxes: Vec<JunctionBox>,
+    edges: Vec<(usize, usize, f32)>,
+}
+
 struct UnionFind {
     parent: Vec<usize>,
     rank: Vec<u8>,
@@ -205,27 +200,3 @@ impl Display for JunctionBox {
         write!(f, "{},{},{}", self.x, self.y, self.z)
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one_for_iteration(
-            &advent_of_code::template::read_file_input("examples", DAY, "example_1.txt"),
-            10,
-        );
-        assert_eq!(result, Some(40));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(25272));
-    }
-}
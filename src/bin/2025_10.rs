@@ -443,78 +443,3 @@ impl Display for Machine {
         write!(f, "[{}]", target_state_string)
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(7));
-    }
-
-    #[test]
-    fn test_part_one_simple1() {
-        let result = part_one(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1_simple1.txt",
-        ));
-        assert_eq!(result, Some(2));
-    }
-
-    #[test]
-    fn test_part_one_simple3() {
-        let result = part_one(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1_simple3.txt",
-        ));
-        assert_eq!(result, Some(2));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(33));
-    }
-
-    #[test]
-    fn test_part_two_simple1_full() {
-        let result = part_two(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1_simple1_full.txt",
-        ));
-        assert_eq!(result, Some(10));
-    }
-
-    #[test]
-    fn test_part_two_simple2_full() {
-        let result = part_two(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1_simple2_full.txt",
-        ));
-        assert_eq!(result, Some(12));
-    }
-
-    #[test]
-    fn test_part_two_simple3_full() {
-        let result = part_two(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1_simple3_full.txt",
-        ));
-        assert_eq!(result, Some(11));
-    }
-}
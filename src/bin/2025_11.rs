@@ -3,17 +3,9 @@ use std::{
     collections::{BinaryHeap, HashMap},
 };
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(11, parse = parse);
 
-pub fn part_one(input: &str) -> Option<u64> {
-    solve(input, false)
-}
-
-pub fn part_two(input: &str) -> Option<u64> {
-    solve(input, true)
-}
-
-fn solve(input: &str, part_2: bool) -> Option<u64> {
+pub fn parse(input: &str) -> Devices<'_> {
     let inputs = input.trim_end().lines();
 
     let mut index_map: HashMap<usize, &str> = HashMap::new();
@@ -36,6 +28,31 @@ fn solve(input: &str, part_2: bool) -> Option<u64> {
     index_map.insert(out_index, "out");
     label_map.insert("out", out_index);
 
+    Devices {
+        index_map,
+        label_map,
+        connection_map,
+        out_index,
+    }
+}
+
+pub fn part_one(input: &Devices) -> Option<u64> {
+    solve(input, false)
+}
+
+pub fn part_two(input: &Devices) -> Option<u64> {
+    solve(input, true)
+}
+
+fn solve(input: &Devices, part_2: bool) -> Option<u64> {
+    let Devices {
+        index_map,
+        label_map,
+        connection_map,
+        out_index,
+    } = input;
+    let out_index = *out_index;
+
     let mut to_visit_set: BinaryHeap<Reverse<(u64, usize)>> = BinaryHeap::new();
     // do for each out
     to_visit_set.push(Reverse((0_u64, out_index)));
@@ -51,9 +68,9 @@ fn solve(input: &str, part_2: bool) -> Option<u64> {
     let paths = if !part_2 {
         let mut ways_map: HashMap<usize, u64> = HashMap::new();
         count_paths_dfs(
-            &index_map,
-            &label_map,
-            &connection_map,
+            index_map,
+            label_map,
+            connection_map,
             &mut ways_map,
             *start_index,
             out_index,
@@ -61,9 +78,9 @@ fn solve(input: &str, part_2: bool) -> Option<u64> {
     } else {
         let mut state_cache: HashMap<(usize, bool, bool), u64> = HashMap::new();
         count_paths_dfs_part_2(
-            &index_map,
-            &label_map,
-            &connection_map,
+            index_map,
+            label_map,
+            connection_map,
             &mut state_cache,
             *start_index,
             out_index,
@@ -75,6 +92,13 @@ fn solve(input: &str, part_2: bool) -> Option<u64> {
     Some(paths)
 }
 
+pub struct Devices<'a> {
+    index_map: HashMap<usize, &'a str>,
+    label_map: HashMap<&'a str, usize>,
+    connection_map: HashMap<&'a str, Vec<&'a str>>,
+    out_index: usize,
+}
+
 fn count_paths_dfs(
     index_map: &HashMap<usize, &str>,
     label_map: &HashMap<&str, usize>,
@@ -170,28 +194,3 @@ fn count_paths_dfs_part_2(
     state_cache.insert(current_state, total);
     total
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_1.txt",
-        ));
-        assert_eq!(result, Some(5));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_input(
-            "examples",
-            DAY,
-            "example_2.txt",
-        ));
-        assert_eq!(result, Some(2));
-    }
-}
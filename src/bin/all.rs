@@ -0,0 +1,15 @@
+// Bundles every solution into a single binary, see `advent_of_code::template::registry`.
+advent_of_code::registry! {
+    day_2025_01 => "2025_01.rs",
+    day_2025_02 => "2025_02.rs",
+    day_2025_03 => "2025_03.rs",
+    day_2025_04 => "2025_04.rs",
+    day_2025_05 => "2025_05.rs",
+    day_2025_06 => "2025_06.rs",
+    day_2025_07 => "2025_07.rs",
+    day_2025_08 => "2025_08.rs",
+    day_2025_09 => "2025_09.rs",
+    day_2025_10 => "2025_10.rs",
+    day_2025_11 => "2025_11.rs",
+    day_2025_12 => "2025_12.rs",
+}
@@ -1,4 +1,13 @@
 pub mod template;
 
+// NOTE: declared once for the whole crate so that binaries bundling several days share it.
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+#[cfg(not(feature = "dhat-heap"))]
+#[global_allocator]
+static ALLOC: template::heap::CountingAlloc = template::heap::CountingAlloc;
+
 // Use this file to add helper functions and additional modules.
 pub mod advent_stdlib;
@@ -1,4 +1,7 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::PuzzleId;
+use advent_of_code::template::commands::{
+    all, download, leaderboard, read, scaffold, solve, time, verify,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
@@ -7,75 +10,246 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{
+        Day, PuzzleId, RunOptions, Year,
+        commands::scaffold::AnswerType,
+        regression::{DEFAULT_THRESHOLD, parse_threshold},
+        runner::{
+            BENCH_TIME_FLAG, BenchConfig, MAX_SAMPLES_FLAG, MIN_SAMPLES_FLAG, WARMUP_FLAG,
+            parse_seconds,
+        },
+    };
     use std::process;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            year: Year,
+            /// `None` downloads every unlocked day, with `--all`.
+            day: Option<Day>,
         },
         Read {
-            day: Day,
+            id: PuzzleId,
+        },
+        Leaderboard {
+            year: Year,
+            id: Option<String>,
+            file: Option<String>,
+            store: bool,
         },
         Scaffold {
-            day: Day,
+            id: PuzzleId,
             download: bool,
             overwrite: bool,
+            answer_type: AnswerType,
         },
         Solve {
-            day: Day,
+            id: PuzzleId,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            examples: bool,
+            input: Option<String>,
         },
         All {
-            release: bool,
+            year: Year,
+            options: RunOptions,
+        },
+        Verify {
+            year: Year,
+            all: bool,
+            day: Option<Day>,
+            options: RunOptions,
         },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
+            check: Option<f64>,
+            variants: bool,
+            options: RunOptions,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            year: Year,
+        },
+    }
+
+    /// The year passed with `--year`, `AOC_YEAR` otherwise.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        let year: Option<Year> = match args.opt_value_from_str("--year")? {
+            Some(year) => Some(year),
+            None => Year::from_env()?,
+        };
+
+        year.ok_or_else(|| "no year given, pass `--year <year>` or set AOC_YEAR.".into())
+    }
+
+    fn parse_run_options(args: &mut pico_args::Arguments) -> Result<RunOptions, pico_args::Error> {
+        let jobs: Option<usize> = args.opt_value_from_str(["-j", "--jobs"])?;
+
+        Ok(RunOptions {
+            jobs: jobs.unwrap_or(1).max(1),
+            timeout: args.opt_value_from_fn("--timeout", parse_seconds)?,
+            part_timeout: args.opt_value_from_fn("--part-timeout", parse_seconds)?,
+            ..Default::default()
+        })
+    }
+
+    fn parse_bench_config(args: &mut pico_args::Arguments) -> Result<BenchConfig, String> {
+        let parse = |args: &mut pico_args::Arguments| -> Result<BenchConfig, pico_args::Error> {
+            Ok(BenchConfig {
+                bench_time: args.opt_value_from_fn(BENCH_TIME_FLAG, parse_seconds)?,
+                min_samples: args.opt_value_from_str(MIN_SAMPLES_FLAG)?,
+                max_samples: args.opt_value_from_str(MAX_SAMPLES_FLAG)?,
+                warmup: args.opt_value_from_str(WARMUP_FLAG)?,
+            })
+        };
+
+        // NOTE: flags take precedence over the `AOC_BENCH_*` env vars, which are checked here
+        // as well so that a typo does not fall back to the defaults unnoticed.
+        let config = parse(args)
+            .map_err(|e| e.to_string())?
+            .or(BenchConfig::from_env()?);
+
+        if let (Some(min), Some(max)) = (config.min_samples, config.max_samples)
+            && min > max
+        {
+            return Err(format!(
+                "{MIN_SAMPLES_FLAG} ({min}) must not be larger than {MAX_SAMPLES_FLAG} ({max})"
+            ));
+        }
+
+        Ok(config)
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+        let year = parse_year(&mut args)?;
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
-                release: args.contains("--release"),
+                year,
+                options: RunOptions {
+                    is_release: args.contains("--release"),
+                    ..parse_run_options(&mut args)?
+                },
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let threshold = args.opt_value_from_fn("--threshold", parse_threshold)?;
+                let check = args
+                    .contains("--check")
+                    .then(|| threshold.unwrap_or(DEFAULT_THRESHOLD));
+
+                let variants = args.contains("--variants");
+
+                if check.is_some() && store {
+                    return Err("`--check` can not be combined with `--store`.".into());
+                }
+
+                if variants && (check.is_some() || all) {
+                    return Err(
+                        "`--variants` can not be combined with `--check` or `--all`.".into(),
+                    );
+                }
+                // NOTE: timings stay serial unless asked for, parallel runs skew measurements.
+                let options = RunOptions {
+                    bench: parse_bench_config(&mut args)?,
+                    ..parse_run_options(&mut args)?
+                };
+
+                let day = args.opt_free_from_str()?;
+
+                if variants && day.is_none() {
+                    return Err("`--variants` needs a day to bench.".into());
+                }
 
                 AppArguments::Time {
+                    year,
                     all,
-                    day: args.opt_free_from_str()?,
+                    day,
                     store,
+                    check,
+                    variants,
+                    options,
                 }
             }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
-            },
+            Some("verify") => {
+                let all = args.contains("--all");
+                let options = parse_run_options(&mut args)?;
+
+                AppArguments::Verify {
+                    year,
+                    all,
+                    day: args.opt_free_from_str()?,
+                    options,
+                }
+            }
+            Some("download") => {
+                let all = args.contains("--all");
+                let day = args.opt_free_from_str()?;
+
+                if all == day.is_some() {
+                    return Err("`download` expects either a day or `--all`.".into());
+                }
+
+                AppArguments::Download { year, day }
+            }
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                id: PuzzleId::new(year, args.free_from_str()?),
             },
+            Some("leaderboard") => {
+                let file = args.opt_value_from_str("--file")?;
+                let store = args.contains("--store");
+                let id = args.opt_free_from_str()?;
+
+                if file.is_some() == id.is_some() {
+                    return Err("`leaderboard` expects either a leaderboard id or `--file`.".into());
+                }
+
+                AppArguments::Leaderboard {
+                    year,
+                    id,
+                    file,
+                    store,
+                }
+            }
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                id: PuzzleId::new(year, args.free_from_str()?),
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                answer_type: args.opt_value_from_str("--answer")?.unwrap_or_default(),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
+            Some("solve") => {
+                let submit = args.opt_value_from_str("--submit")?;
+                let examples = args.contains("--examples");
+                let input: Option<String> = args.opt_value_from_str("--input")?;
+
+                if examples && submit.is_some() {
+                    return Err("`--examples` can not be combined with `--submit`.".into());
+                }
+
+                if input.is_some() && (examples || submit.is_some()) {
+                    return Err(
+                        "`--input` can not be combined with `--examples` or `--submit`.".into(),
+                    );
+                }
+
+                AppArguments::Solve {
+                    id: PuzzleId::new(year, args.free_from_str()?),
+                    release: args.contains("--release"),
+                    submit,
+                    dhat: args.contains("--dhat"),
+                    examples,
+                    input,
+                }
+            }
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today { year },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -102,33 +276,67 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
+            AppArguments::All { year, options } => all::handle(year, &options),
+            AppArguments::Time {
+                year,
                 day,
+                all,
+                store,
+                check,
+                variants,
+                options,
+            } => match day {
+                Some(day) if variants => {
+                    time::handle_variants(PuzzleId::new(year, day), store, &options);
+                }
+                _ => time::handle(year, day, all, store, check, &options),
+            },
+            AppArguments::Verify {
+                year,
+                day,
+                all,
+                options,
+            } => verify::handle(year, day, all, &options),
+            AppArguments::Download { year, day } => match day {
+                Some(day) => download::handle(PuzzleId::new(year, day)),
+                None => download::handle_all(year),
+            },
+            AppArguments::Read { id } => read::handle(id),
+            AppArguments::Leaderboard {
+                year,
+                id,
+                file,
+                store,
+            } => {
+                leaderboard::handle(year, id.as_deref(), file.as_deref(), store);
+            }
+            AppArguments::Scaffold {
+                id,
                 download,
                 overwrite,
+                answer_type,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(id, overwrite, answer_type);
                 if download {
-                    download::handle(day);
+                    download::handle(id);
                 }
             }
             AppArguments::Solve {
-                day,
+                id,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                examples,
+                input,
+            } => solve::handle(id, release, dhat, submit, examples, input.as_deref()),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
+            AppArguments::Today { year } => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                        let id = PuzzleId::new(year, day);
+                        scaffold::handle(id, false, Default::default());
+                        download::handle(id);
+                        read::handle(id)
                     }
                     None => {
                         eprintln!(
@@ -0,0 +1,225 @@
+/// Verified answers of a day, stored in `data/<year>/day_XX/answers.json`.
+/// Answers are recorded when a submission is accepted, or can be added by hand:
+/// `{ "part_1": "1234", "part_2": null }`.
+use std::{collections::HashMap, fs, io, path::Path, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::answers_path;
+use crate::template::PuzzleId;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    /// Reads the answers of a puzzle. A missing file yields no answers.
+    pub fn read(id: PuzzleId) -> Result<Self, String> {
+        let path = format!(answers_path!(), id.year, id.day);
+
+        if !Path::new(&path).exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        Answers::from_str(&content).map_err(|e| format!("{path}: {e}"))
+    }
+
+    /// Writes the answers of a puzzle, overwriting the previous file.
+    pub fn store(&self, id: PuzzleId) -> Result<(), io::Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(format!(answers_path!(), id.year, id.day))?;
+        json.format_to(&mut file)
+    }
+
+    /// Records the accepted answer of a single part and stores it right away.
+    pub fn record(id: PuzzleId, part: u8, answer: &str) -> Result<(), String> {
+        let mut answers = Answers::read(id)?;
+        answers.set(part, answer.to_string());
+        answers.store(id).map_err(|e| e.to_string())
+    }
+
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&String> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part_1 = Some(answer),
+            2 => self.part_2 = Some(answer),
+            _ => {}
+        }
+    }
+
+    /// Whether no part has a recorded answer yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.part_1.is_none() && self.part_2.is_none()
+    }
+}
+
+/// Compares an answer with the recorded one, ignoring surrounding whitespace.
+#[must_use]
+pub fn matches(expected: &str, actual: &str) -> bool {
+    expected.trim() == actual.trim()
+}
+
+/// Brings an answer into the form it is submitted in: surrounding whitespace is trimmed,
+/// everything else is kept as printed, e.g. the leading zeros of `007`.
+/// Multi-line answers, e.g. letters drawn on a grid, have to be converted to a single line
+/// by the solution and yield an error.
+pub fn normalize(answer: &str) -> Result<String, String> {
+    let answer = answer.trim();
+
+    if answer.is_empty() {
+        return Err("the answer is empty.".into());
+    }
+
+    if answer.contains('\n') {
+        return Err("multi-line answers have to be converted to a single line first.".into());
+    }
+
+    Ok(answer.to_string())
+}
+
+/// Keeps multi-line and long answers on a single table cell.
+#[must_use]
+pub fn shorten(answer: Option<&str>) -> String {
+    const MAX_LEN: usize = 20;
+
+    let Some(answer) = answer else {
+        return "-".into();
+    };
+
+    let line = answer.trim().lines().next().unwrap_or_default();
+
+    if line.chars().count() > MAX_LEN || answer.trim().contains('\n') {
+        let short: String = line.chars().take(MAX_LEN - 1).collect();
+        format!("{short}…")
+    } else {
+        line.to_string()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "part_1".into(),
+            value
+                .part_1
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "part_2".into(),
+            value
+                .part_2
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("answers are not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answers to be a JSON object.")?;
+
+        // NOTE: answers entered by hand may be numbers, they are compared as strings.
+        let answer = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(JsonValue::String(x)) => Ok(Some(x.clone())),
+            Some(JsonValue::Number(x)) if x.fract() == 0.0 => Ok(Some(format!("{x:.0}"))),
+            Some(_) => Err(format!("Expected answers.{key} to be null or string.")),
+        };
+
+        Ok(Answers {
+            part_1: answer("part_1")?,
+            part_2: answer("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, matches, normalize};
+    use std::str::FromStr;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn roundtrips_answers() {
+        let answers = Answers {
+            part_1: Some("1234".into()),
+            part_2: None,
+        };
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::from_str(&json).unwrap(), answers);
+    }
+
+    #[test]
+    fn reads_handwritten_answers() {
+        let answers = Answers::from_str(r#"{ "part_1": 1234, "part_2": "ABC" }"#).unwrap();
+        assert_eq!(answers.get(1), Some(&"1234".to_string()));
+        assert_eq!(answers.get(2), Some(&"ABC".to_string()));
+    }
+
+    #[test]
+    fn handles_missing_parts() {
+        let answers = Answers::from_str(r#"{ "part_1": "1" }"#).unwrap();
+        assert_eq!(answers.get(2), None);
+        assert_eq!(Answers::from_str("{}").unwrap().is_empty(), true);
+    }
+
+    #[test]
+    fn rejects_invalid_answers() {
+        assert_eq!(
+            Answers::from_str(r#"{ "part_1": [] }"#),
+            Err("Expected answers.part_1 to be null or string.".into())
+        );
+    }
+
+    #[test]
+    fn compares_answers() {
+        assert_eq!(matches("12", "12\n"), true);
+        assert_eq!(matches("12", "13"), false);
+    }
+
+    #[test]
+    fn normalizes_answers() {
+        assert_eq!(normalize(" 42\n"), Ok("42".into()));
+        assert_eq!(normalize("007"), Ok("007".into()));
+        assert_eq!(normalize("+7"), Ok("+7".into()));
+        assert_eq!(normalize("-12"), Ok("-12".into()));
+        assert_eq!(
+            normalize("340282366920938463463374607431768211455"),
+            Ok("340282366920938463463374607431768211455".into())
+        );
+        assert_eq!(normalize("ABCD"), Ok("ABCD".into()));
+        assert_eq!(normalize("1,2,3"), Ok("1,2,3".into()));
+    }
+
+    #[test]
+    fn rejects_unsubmittable_answers() {
+        assert_eq!(normalize("  ").is_err(), true);
+        assert_eq!(normalize("#..#\n.##.").is_err(), true);
+    }
+}
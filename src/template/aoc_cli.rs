@@ -1,11 +1,14 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
+    env,
     fmt::Display,
-    process::{Command, Output, Stdio},
+    fs,
+    process::{self, Command, Output, Stdio},
+    time::Duration,
 };
 
-use crate::template::Day;
-use crate::{input_path, puzzle_path};
+use crate::template::PuzzleId;
+use crate::template::backend::{BackendError, PuzzleBackend};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -26,6 +29,86 @@ impl Display for AocCommandError {
     }
 }
 
+/// Hint that the server gives along with a wrong answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// Verdict of the server on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+    },
+    /// An answer was submitted too recently. `wait` is the remaining time, if the server reported it.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    AlreadySolved,
+    /// The part can not be submitted yet, or was already solved.
+    /// The server does not tell these two cases apart for part 2.
+    WrongLevel,
+}
+
+impl SubmitOutcome {
+    /// Parses the response text of a submission of `part`.
+    #[must_use]
+    pub fn parse(response: &str, part: u8) -> Option<Self> {
+        // NOTE: aoc-cli wraps lines, phrases may span several of them.
+        let response = response.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if response.contains("That's the right answer") {
+            Some(SubmitOutcome::Correct)
+        } else if response.contains("That's not the right answer") {
+            let hint = if response.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if response.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Some(SubmitOutcome::Incorrect { hint })
+        } else if response.contains("You gave an answer too recently") {
+            Some(SubmitOutcome::RateLimited {
+                wait: parse_wait(&response),
+            })
+        } else if response.contains("You don't seem to be solving the right level") {
+            // part 1 is always open until it is solved.
+            if part == 1 {
+                Some(SubmitOutcome::AlreadySolved)
+            } else {
+                Some(SubmitOutcome::WrongLevel)
+            }
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses the remaining time of a rate limit, e.g. `You have 1m 37s left to wait`.
+fn parse_wait(response: &str) -> Option<Duration> {
+    let start = response.find("You have ")? + "You have ".len();
+    let end = start + response[start..].find(" left to wait")?;
+
+    response[start..end]
+        .split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.len() - 1);
+            let value = value.parse::<u64>().ok()?;
+            match unit {
+                "s" => Some(value),
+                "m" => Some(value * 60),
+                "h" => Some(value * 3600),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
 pub fn check() -> Result<(), AocCommandError> {
     Command::new("aoc")
         .arg("-V")
@@ -34,86 +117,89 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+/// Backend that calls the aoc-cli command-line.
+pub struct AocCli;
 
-    let args = build_args(
-        "read",
-        &[
-            "--description-only".into(),
-            "--puzzle-file".into(),
-            puzzle_path,
-        ],
-        day,
-    );
+impl PuzzleBackend for AocCli {
+    fn name(&self) -> &'static str {
+        "aoc-cli"
+    }
 
-    call_aoc_cli(&args)
-}
+    fn input(&self, id: PuzzleId) -> Result<String, BackendError> {
+        // NOTE: aoc-cli only writes inputs to files, hand it a temporary one.
+        let input_path = env::temp_dir().join(format!("aoc_{}_input_{id}.txt", process::id()));
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
-
-    let args = build_args(
-        "download",
-        &[
-            "--overwrite".into(),
-            "--input-file".into(),
-            input_path.to_string(),
-            "--puzzle-file".into(),
-            puzzle_path.to_string(),
-        ],
-        day,
-    );
-
-    let output = call_aoc_cli(&args)?;
-    println!("---");
-    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-    Ok(output)
-}
+        let args = build_args(
+            "download",
+            &[
+                "--overwrite".into(),
+                "--input-only".into(),
+                "--input-file".into(),
+                input_path.to_string_lossy().to_string(),
+            ],
+            id,
+        );
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
-    // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
-    args.push(part.to_string());
-    args.push(result.to_string());
-    call_aoc_cli(&args)
-}
+        call_aoc_cli_captured(&args)?;
 
-fn get_input_path(day: Day) -> String {
-    format!(input_path!(), day)
-}
+        let input =
+            fs::read_to_string(&input_path).map_err(|e| BackendError::Failed(e.to_string()));
+        let _ = fs::remove_file(&input_path);
+        input
+    }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!(puzzle_path!(), day)
-}
+    fn description(&self, id: PuzzleId) -> Result<String, BackendError> {
+        // NOTE: stdout is rendered for the terminal, the puzzle file holds the Markdown.
+        let puzzle_path = env::temp_dir().join(format!("aoc_{}_puzzle_{id}.md", process::id()));
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
+        let args = build_args(
+            "download",
+            &[
+                "--overwrite".into(),
+                "--puzzle-only".into(),
+                "--puzzle-file".into(),
+                puzzle_path.to_string_lossy().to_string(),
+            ],
+            id,
+        );
+
+        call_aoc_cli_captured(&args)?;
+
+        let description =
+            fs::read_to_string(&puzzle_path).map_err(|e| BackendError::Failed(e.to_string()));
+        let _ = fs::remove_file(&puzzle_path);
+        description
     }
-}
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
-    let mut cmd_args = args.to_vec();
+    fn submit(&self, id: PuzzleId, part: u8, answer: &str) -> Result<String, BackendError> {
+        // workaround: the argument order is inverted for submit.
+        let mut args = build_args("submit", &[], id);
+        args.push(part.to_string());
+        args.push(answer.to_string());
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+        let output = call_aoc_cli_captured(&args)?;
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
     }
+}
 
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+fn build_args(command: &str, args: &[String], id: PuzzleId) -> Vec<String> {
+    let mut cmd_args = args.to_vec();
+
+    cmd_args.append(&mut vec![
+        "--year".into(),
+        id.year.to_string(),
+        "--day".into(),
+        id.day.to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
 
-fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
-    // println!("Calling >aoc with: {}", args.join(" "));
+fn call_aoc_cli_captured(args: &[String]) -> Result<Output, AocCommandError> {
     let output = Command::new("aoc")
         .args(args)
-        .stdout(Stdio::inherit())
+        .stdout(Stdio::piped())
         .stderr(Stdio::inherit())
         .output()
         .map_err(|_| AocCommandError::CommandNotCallable)?;
@@ -124,3 +210,69 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         Err(AocCommandError::BadExitStatus(output))
     }
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Hint, SubmitOutcome};
+    use std::time::Duration;
+
+    #[test]
+    fn parses_correct_answers() {
+        let response = include_str!("fixtures/submit/correct.txt");
+        assert_eq!(
+            SubmitOutcome::parse(response, 1),
+            Some(SubmitOutcome::Correct)
+        );
+    }
+
+    #[test]
+    fn parses_incorrect_answers() {
+        let cases = [
+            (
+                include_str!("fixtures/submit/too_high.txt"),
+                Some(Hint::TooHigh),
+            ),
+            (
+                include_str!("fixtures/submit/too_low.txt"),
+                Some(Hint::TooLow),
+            ),
+            (include_str!("fixtures/submit/incorrect.txt"), None),
+        ];
+
+        for (response, hint) in cases {
+            assert_eq!(
+                SubmitOutcome::parse(response, 1),
+                Some(SubmitOutcome::Incorrect { hint })
+            );
+        }
+    }
+
+    #[test]
+    fn parses_rate_limits() {
+        let response = include_str!("fixtures/submit/rate_limited.txt");
+        assert_eq!(
+            SubmitOutcome::parse(response, 2),
+            Some(SubmitOutcome::RateLimited {
+                wait: Some(Duration::from_secs(97))
+            })
+        );
+    }
+
+    #[test]
+    fn parses_wrong_levels() {
+        let response = include_str!("fixtures/submit/wrong_level.txt");
+        assert_eq!(
+            SubmitOutcome::parse(response, 1),
+            Some(SubmitOutcome::AlreadySolved)
+        );
+        assert_eq!(
+            SubmitOutcome::parse(response, 2),
+            Some(SubmitOutcome::WrongLevel)
+        );
+    }
+
+    #[test]
+    fn ignores_unknown_responses() {
+        assert_eq!(SubmitOutcome::parse("Please log in.", 1), None);
+    }
+}
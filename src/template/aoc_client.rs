@@ -0,0 +1,275 @@
+/// Built-in client for the Advent of Code website, the backend used when aoc-cli is not installed.
+/// Requests are authenticated with the session cookie from `AOC_SESSION` or the
+/// `~/.adventofcode.session` file that aoc-cli reads as well. `AOC_BASE_URL` points
+/// the client at another server, e.g. a local stub.
+use std::{env, fmt::Display, io};
+
+use crate::template::backend::{BackendError, PuzzleBackend, read_session};
+use crate::template::{PuzzleId, Year, puzzle};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    MissingSession,
+    Request(ureq::Error),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found, set AOC_SESSION or create \"~/.adventofcode.session\"."
+            ),
+            AocClientError::Request(ureq::Error::StatusCode(400 | 500)) => write!(
+                f,
+                "the server rejected the request, the session cookie may have expired."
+            ),
+            AocClientError::Request(ureq::Error::StatusCode(404)) => {
+                write!(f, "the puzzle was not found, it may not be unlocked yet.")
+            }
+            AocClientError::Request(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        AocClientError::Request(e)
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    #[must_use]
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        AocClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent,
+        }
+    }
+
+    /// Configures the client from `AOC_BASE_URL` and the session cookie.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = read_session().ok_or(AocClientError::MissingSession)?;
+
+        Ok(AocClient::new(&base_url, &session))
+    }
+
+    /// The page of a day, as HTML.
+    pub fn puzzle(&self, id: PuzzleId) -> Result<String, AocClientError> {
+        self.get(&self.day_url(id))
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        Ok(self
+            .agent
+            .get(url)
+            .header("Cookie", &self.cookie())
+            .call()?
+            .body_mut()
+            .read_to_string()?)
+    }
+
+    fn post(&self, url: &str, form: [(&str, String); 2]) -> Result<String, AocClientError> {
+        Ok(self
+            .agent
+            .post(url)
+            .header("Cookie", &self.cookie())
+            .send_form(form)?
+            .body_mut()
+            .read_to_string()?)
+    }
+
+    fn day_url(&self, id: PuzzleId) -> String {
+        format!("{}/{}/day/{}", self.base_url, id.year, id.day.into_inner())
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+impl PuzzleBackend for AocClient {
+    fn name(&self) -> &'static str {
+        "the native client"
+    }
+
+    fn input(&self, id: PuzzleId) -> Result<String, BackendError> {
+        Ok(self.get(&format!("{}/input", self.day_url(id)))?)
+    }
+
+    fn description(&self, id: PuzzleId) -> Result<String, BackendError> {
+        Ok(puzzle::to_markdown(&self.puzzle(id)?))
+    }
+
+    fn page(&self, id: PuzzleId) -> Result<String, BackendError> {
+        Ok(self.puzzle(id)?)
+    }
+
+    fn submit(&self, id: PuzzleId, part: u8, answer: &str) -> Result<String, BackendError> {
+        let form = [("level", part.to_string()), ("answer", answer.to_string())];
+        let response = self.post(&format!("{}/answer", self.day_url(id)), form)?;
+        Ok(puzzle::to_markdown(&response))
+    }
+
+    fn leaderboard(&self, year: Year, id: &str) -> Result<String, BackendError> {
+        let url = format!(
+            "{}/{year}/leaderboard/private/view/{id}.json",
+            self.base_url
+        );
+        Ok(self.get(&url)?)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::AocClient;
+    use crate::{
+        day,
+        template::{
+            PuzzleId,
+            aoc_cli::{Hint, SubmitOutcome},
+            backend::PuzzleBackend,
+        },
+        year,
+    };
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    /// Serves `responses` in order on a local port and sends back every request it received.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+
+                tx.send(request).unwrap();
+            }
+        });
+
+        (base_url, rx)
+    }
+
+    #[test]
+    fn fetches_inputs() {
+        let (base_url, requests) = serve(vec![(200, "1\n2\n3\n")]);
+        let client = AocClient::new(&base_url, "abc\n");
+
+        let id = PuzzleId::new(year!(2025), day!(7));
+        assert_eq!(client.input(id).unwrap(), "1\n2\n3\n");
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.starts_with("GET /2025/day/7/input "), true);
+        assert_eq!(request.contains("session=abc\r\n"), true);
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, requests) = serve(vec![(
+            200,
+            "<html><main><article><p>That&apos;s not the right answer; your answer is too high.</p></article></main></html>",
+        )]);
+        let client = AocClient::new(&base_url, "abc");
+
+        let id = PuzzleId::new(year!(2016), day!(7));
+        let response = client.submit(id, 2, "42").unwrap();
+        assert_eq!(
+            SubmitOutcome::parse(&response, 2).unwrap(),
+            SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooHigh)
+            }
+        );
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.starts_with("POST /2016/day/7/answer "), true);
+        assert_eq!(request.ends_with("level=2&answer=42"), true);
+    }
+
+    #[test]
+    fn fetches_private_leaderboards() {
+        let (base_url, requests) = serve(vec![(200, r#"{ "event": "2025", "members": {} }"#)]);
+        let client = AocClient::new(&base_url, "abc");
+
+        assert_eq!(
+            client.leaderboard(year!(2025), "3031").unwrap(),
+            r#"{ "event": "2025", "members": {} }"#
+        );
+
+        let request = requests.recv().unwrap();
+        assert_eq!(
+            request.starts_with("GET /2025/leaderboard/private/view/3031.json "),
+            true
+        );
+    }
+
+    #[test]
+    fn reports_missing_puzzles() {
+        let (base_url, _requests) = serve(vec![(404, "Not Found")]);
+        let client = AocClient::new(&base_url, "abc");
+
+        let error = client
+            .puzzle(PuzzleId::new(year!(2025), day!(25)))
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the puzzle was not found, it may not be unlocked yet."
+        );
+    }
+}
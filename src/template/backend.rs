@@ -0,0 +1,748 @@
+/// Backends that talk to Advent of Code: download inputs, fetch descriptions and submit answers.
+/// `AOC_BACKEND` selects one of `aoc-cli`, `native` or `fake`. By default, aoc-cli is used if it
+/// is installed and the native client otherwise, if it was enabled.
+/// The inputs returned by the server are cached per account, batch downloads throttle their
+/// requests to the server.
+use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet},
+    env,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::template::answers::{self, Answers};
+use crate::template::aoc_cli::{self, AocCli, AocCommandError, SubmitOutcome};
+#[cfg(feature = "native_client")]
+use crate::template::aoc_client::{AocClient, AocClientError};
+use crate::template::{Day, PuzzleId, Year, puzzle};
+
+/// Environment variable that selects the backend.
+pub const BACKEND_VAR: &str = "AOC_BACKEND";
+
+/// Environment variable with the directory the fake backend serves puzzles from.
+pub const FAKE_DIR_VAR: &str = "AOC_FAKE_DIR";
+
+/// Environment variable with the minimum delay between requests to the server, in milliseconds.
+pub const REQUEST_DELAY_VAR: &str = "AOC_REQUEST_DELAY";
+
+/// Environment variable with the session cookie of the account, see `read_session`.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable with the directory inputs are cached in.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+/// Minimum delay between requests of batch downloads, unless `AOC_REQUEST_DELAY` is set.
+pub const DEFAULT_REQUEST_DELAY: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+pub enum BackendError {
+    /// The selected backend can not be used.
+    Unavailable(String),
+    AocCli(AocCommandError),
+    #[cfg(feature = "native_client")]
+    Native(AocClientError),
+    Failed(String),
+    UnknownResponse(String),
+}
+
+impl Display for BackendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackendError::Unavailable(reason) | BackendError::Failed(reason) => {
+                write!(f, "{reason}")
+            }
+            BackendError::AocCli(e) => write!(f, "failed to call aoc-cli: {e}"),
+            #[cfg(feature = "native_client")]
+            BackendError::Native(e) => write!(f, "{e}"),
+            BackendError::UnknownResponse(_) => write!(
+                f,
+                "the server returned a response that could not be understood."
+            ),
+        }
+    }
+}
+
+impl From<AocCommandError> for BackendError {
+    fn from(e: AocCommandError) -> Self {
+        BackendError::AocCli(e)
+    }
+}
+
+#[cfg(feature = "native_client")]
+impl From<AocClientError> for BackendError {
+    fn from(e: AocClientError) -> Self {
+        BackendError::Native(e)
+    }
+}
+
+pub trait PuzzleBackend {
+    /// Name of the backend, shown to the user.
+    fn name(&self) -> &'static str;
+
+    /// The personal puzzle input of a day.
+    fn input(&self, id: PuzzleId) -> Result<String, BackendError>;
+
+    /// The description of a day, as far as it is unlocked.
+    fn description(&self, id: PuzzleId) -> Result<String, BackendError>;
+
+    /// Submits the answer of a part and returns the response of the server, as text.
+    fn submit(&self, id: PuzzleId, part: u8, answer: &str) -> Result<String, BackendError>;
+
+    /// The page of a day as HTML, which examples are extracted from.
+    fn page(&self, _id: PuzzleId) -> Result<String, BackendError> {
+        Err(BackendError::Unavailable(format!(
+            "{} does not provide puzzle pages.",
+            self.name()
+        )))
+    }
+
+    /// The private leaderboard `id` of `year`, as JSON.
+    fn leaderboard(&self, _year: Year, _id: &str) -> Result<String, BackendError> {
+        Err(BackendError::Unavailable(format!(
+            "{} does not provide private leaderboards.",
+            self.name()
+        )))
+    }
+}
+
+/// The backend selected by `AOC_BACKEND`, see the module docs.
+pub fn from_env() -> Result<Box<dyn PuzzleBackend>, BackendError> {
+    select(env::var(BACKEND_VAR).ok().as_deref(), None)
+}
+
+/// The backend selected by `AOC_BACKEND`, which sends its requests to the server at least
+/// `AOC_REQUEST_DELAY` apart. Used by batch downloads.
+pub fn throttled_from_env() -> Result<Box<dyn PuzzleBackend>, BackendError> {
+    let min_delay = parse_request_delay(env::var(REQUEST_DELAY_VAR).ok().as_deref())?;
+    select(env::var(BACKEND_VAR).ok().as_deref(), Some(min_delay))
+}
+
+/// The delay in milliseconds of `AOC_REQUEST_DELAY`, `DEFAULT_REQUEST_DELAY` if it is unset.
+fn parse_request_delay(value: Option<&str>) -> Result<Duration, BackendError> {
+    value.map_or(Ok(DEFAULT_REQUEST_DELAY), |x| {
+        x.trim().parse().map(Duration::from_millis).map_err(|e| {
+            BackendError::Unavailable(format!("invalid value for {REQUEST_DELAY_VAR}: {e}"))
+        })
+    })
+}
+
+/// The backend called `name`, or the default one. Requests to the server are throttled
+/// to `min_delay` if it is set.
+pub fn select(
+    name: Option<&str>,
+    min_delay: Option<Duration>,
+) -> Result<Box<dyn PuzzleBackend>, BackendError> {
+    let remote = |backend| remote(backend, min_delay);
+
+    match name {
+        Some("aoc-cli") => {
+            aoc_cli::check().map_err(|_| BackendError::Unavailable(not_installed_message()))?;
+            Ok(remote(Box::new(AocCli)))
+        }
+        Some("native") => native().map(remote),
+        Some("fake") => {
+            let dir = env::var(FAKE_DIR_VAR).map_err(|_| {
+                BackendError::Unavailable(format!(
+                    "{FAKE_DIR_VAR} has to be set for the fake backend."
+                ))
+            })?;
+            Ok(Box::new(FakeBackend::from_dir(Path::new(&dir))?))
+        }
+        Some(x) => Err(BackendError::Unavailable(format!(
+            "unknown backend `{x}`, expecting one of aoc-cli, native or fake."
+        ))),
+        None if aoc_cli::check().is_ok() => Ok(remote(Box::new(AocCli))),
+        None if cfg!(feature = "native_client") => native().map(remote),
+        None => Err(BackendError::Unavailable(not_installed_message())),
+    }
+}
+
+#[cfg(feature = "native_client")]
+fn native() -> Result<Box<dyn PuzzleBackend>, BackendError> {
+    Ok(Box::new(AocClient::from_env()?))
+}
+
+#[cfg(not(feature = "native_client"))]
+fn native() -> Result<Box<dyn PuzzleBackend>, BackendError> {
+    Err(BackendError::Unavailable(
+        "the native client is not enabled, build with the `native_client` feature.".into(),
+    ))
+}
+
+/// Caches the inputs of a backend that talks to the server, and throttles it to `min_delay`.
+fn remote(backend: Box<dyn PuzzleBackend>, min_delay: Option<Duration>) -> Box<dyn PuzzleBackend> {
+    let backend = match min_delay {
+        Some(min_delay) => Box::new(Throttled::new(backend, min_delay)),
+        None => backend,
+    };
+
+    // NOTE: inputs differ between accounts, they are only cached if the account is known.
+    match (cache_dir(), read_session()) {
+        (Some(dir), Some(session)) => Box::new(Cached::new(backend, account_dir(&dir, &session))),
+        _ => backend,
+    }
+}
+
+/// The session cookie in `AOC_SESSION` or the `~/.adventofcode.session` file that aoc-cli
+/// reads as well.
+pub(crate) fn read_session() -> Option<String> {
+    if let Ok(session) = env::var(SESSION_VAR)
+        && !session.trim().is_empty()
+    {
+        return Some(session);
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    let session = fs::read_to_string(PathBuf::from(home).join(".adventofcode.session")).ok()?;
+
+    (!session.trim().is_empty()).then_some(session)
+}
+
+/// The cache directory of the account with `session`, named after a hash of the cookie
+/// so that the cookie itself is not written to disk.
+fn account_dir(cache_dir: &Path, session: &str) -> PathBuf {
+    // NOTE: FNV-1a, its output does not change between Rust versions like `DefaultHasher`'s may.
+    let hash = session
+        .trim()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+
+    cache_dir.join(format!("{hash:016x}"))
+}
+
+/// `AOC_CACHE_DIR`, or the `advent-of-code` directory in the user's cache.
+fn cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os(CACHE_DIR_VAR) {
+        return Some(PathBuf::from(dir));
+    }
+
+    let cache = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| {
+            let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+            Some(PathBuf::from(home).join(".cache"))
+        })?;
+
+    Some(cache.join("advent-of-code"))
+}
+
+fn not_installed_message() -> String {
+    "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.".into()
+}
+
+/// Submits the answer of a part, forwards the response to the user and reads the verdict.
+pub fn submit(
+    backend: &dyn PuzzleBackend,
+    id: PuzzleId,
+    part: u8,
+    answer: &str,
+) -> Result<SubmitOutcome, BackendError> {
+    let response = backend.submit(id, part, answer)?;
+    println!("{}", response.trim_end());
+
+    SubmitOutcome::parse(&response, part).ok_or(BackendError::UnknownResponse(response))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Waits between the requests of a backend, so that batch downloads do not hammer the server.
+pub struct Throttled {
+    inner: Box<dyn PuzzleBackend>,
+    min_delay: Duration,
+    last_request: Cell<Option<Instant>>,
+}
+
+impl Throttled {
+    #[must_use]
+    pub fn new(inner: Box<dyn PuzzleBackend>, min_delay: Duration) -> Self {
+        Throttled {
+            inner,
+            min_delay,
+            last_request: Cell::new(None),
+        }
+    }
+
+    fn throttle<T>(
+        &self,
+        request: impl FnOnce(&dyn PuzzleBackend) -> Result<T, BackendError>,
+    ) -> Result<T, BackendError> {
+        if let Some(elapsed) = self.last_request.get().map(|last| last.elapsed())
+            && elapsed < self.min_delay
+        {
+            thread::sleep(self.min_delay - elapsed);
+        }
+
+        let result = request(self.inner.as_ref());
+
+        // NOTE: backends report features they lack as unavailable without sending a request.
+        if !matches!(result, Err(BackendError::Unavailable(_))) {
+            self.last_request.set(Some(Instant::now()));
+        }
+
+        result
+    }
+}
+
+impl PuzzleBackend for Throttled {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn input(&self, id: PuzzleId) -> Result<String, BackendError> {
+        self.throttle(|inner| inner.input(id))
+    }
+
+    fn description(&self, id: PuzzleId) -> Result<String, BackendError> {
+        self.throttle(|inner| inner.description(id))
+    }
+
+    fn page(&self, id: PuzzleId) -> Result<String, BackendError> {
+        self.throttle(|inner| inner.page(id))
+    }
+
+    fn submit(&self, id: PuzzleId, part: u8, answer: &str) -> Result<String, BackendError> {
+        self.throttle(|inner| inner.submit(id, part, answer))
+    }
+
+    fn leaderboard(&self, year: Year, id: &str) -> Result<String, BackendError> {
+        self.throttle(|inner| inner.leaderboard(year, id))
+    }
+}
+
+/// Keeps the inputs of a backend in `<dir>/<year>/day_XX/input.txt`. Inputs never change,
+/// so a cached input is served without asking the server again.
+pub struct Cached {
+    inner: Box<dyn PuzzleBackend>,
+    dir: PathBuf,
+}
+
+impl Cached {
+    #[must_use]
+    pub fn new(inner: Box<dyn PuzzleBackend>, dir: PathBuf) -> Self {
+        Cached { inner, dir }
+    }
+
+    fn input_path(&self, id: PuzzleId) -> PathBuf {
+        self.dir
+            .join(id.year.to_string())
+            .join(format!("day_{}", id.day))
+            .join("input.txt")
+    }
+}
+
+impl PuzzleBackend for Cached {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn input(&self, id: PuzzleId) -> Result<String, BackendError> {
+        let path = self.input_path(id);
+
+        if let Ok(input) = fs::read_to_string(&path)
+            && !input.trim().is_empty()
+        {
+            return Ok(input);
+        }
+
+        let input = self.inner.input(id)?;
+
+        let cached = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::write(&path, &input));
+
+        if let Err(e) = cached {
+            eprintln!("Failed to cache input in \"{}\": {e}", path.display());
+        }
+
+        Ok(input)
+    }
+
+    fn description(&self, id: PuzzleId) -> Result<String, BackendError> {
+        self.inner.description(id)
+    }
+
+    fn page(&self, id: PuzzleId) -> Result<String, BackendError> {
+        self.inner.page(id)
+    }
+
+    fn submit(&self, id: PuzzleId, part: u8, answer: &str) -> Result<String, BackendError> {
+        self.inner.submit(id, part, answer)
+    }
+
+    fn leaderboard(&self, year: Year, id: &str) -> Result<String, BackendError> {
+        self.inner.leaderboard(year, id)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Offline backend that serves puzzles from memory and judges answers like the server does.
+/// `from_dir` loads puzzles laid out like `data`: `<year>/day_XX/input.txt`,
+/// `<year>/day_XX/puzzle.md` or the page in `<year>/day_XX/puzzle.html`, and the correct
+/// answers in `<year>/day_XX/answers.json`.
+/// Private leaderboards are read from `<year>/leaderboard_<id>.json`.
+#[derive(Default)]
+pub struct FakeBackend {
+    pub inputs: HashMap<PuzzleId, String>,
+    pub descriptions: HashMap<PuzzleId, String>,
+    pub pages: HashMap<PuzzleId, String>,
+    pub answers: HashMap<PuzzleId, Answers>,
+    pub leaderboards: HashMap<(Year, String), String>,
+    solved: RefCell<HashSet<(PuzzleId, u8)>>,
+    /// Every answer submitted so far.
+    pub submissions: RefCell<Vec<(PuzzleId, u8, String)>>,
+}
+
+impl FakeBackend {
+    pub fn from_dir(dir: &Path) -> Result<Self, BackendError> {
+        let mut backend = FakeBackend::default();
+
+        for entry in read_dir(dir)? {
+            if let Ok(year) = Year::from_str(&entry.file_name().to_string_lossy()) {
+                backend.load_year(year, &entry.path())?;
+            }
+        }
+
+        Ok(backend)
+    }
+
+    fn load_year(&mut self, year: Year, dir: &Path) -> Result<(), BackendError> {
+        for entry in read_dir(dir)? {
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            if let Some(id) = name
+                .strip_prefix("leaderboard_")
+                .and_then(|x| x.strip_suffix(".json"))
+            {
+                let leaderboard = fs::read_to_string(entry.path())
+                    .map_err(|e| BackendError::Failed(format!("{year}/{name}: {e}")))?;
+                self.leaderboards
+                    .insert((year, id.to_string()), leaderboard);
+                continue;
+            }
+
+            let Some(Ok(day)) = name.strip_prefix("day_").map(Day::from_str) else {
+                continue;
+            };
+
+            let id = PuzzleId::new(year, day);
+            let path = entry.path();
+
+            if let Ok(input) = fs::read_to_string(path.join("input.txt")) {
+                self.inputs.insert(id, input);
+            }
+
+            if let Ok(description) = fs::read_to_string(path.join("puzzle.md")) {
+                self.descriptions.insert(id, description);
+            }
+
+            if let Ok(page) = fs::read_to_string(path.join("puzzle.html")) {
+                self.pages.insert(id, page);
+            }
+
+            if let Ok(content) = fs::read_to_string(path.join("answers.json")) {
+                let answers = Answers::from_str(&content).map_err(|e| {
+                    BackendError::Failed(format!("{year}/{name}/answers.json: {e}"))
+                })?;
+                self.answers.insert(id, answers);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn judge(&self, id: PuzzleId, part: u8, answer: &str) -> &'static str {
+        let is_unlocked = part == 1 || self.solved.borrow().contains(&(id, 1));
+        if !is_unlocked || self.solved.borrow().contains(&(id, part)) {
+            return "You don't seem to be solving the right level. Did you already complete it?";
+        }
+
+        let Some(expected) = self.answers.get(&id).and_then(|a| a.get(part)) else {
+            return "That's not the right answer.";
+        };
+
+        if answers::matches(expected, answer) {
+            self.solved.borrow_mut().insert((id, part));
+            return "That's the right answer! You are one gold star closer.";
+        }
+
+        match (expected.parse::<i128>(), answer.parse::<i128>()) {
+            (Ok(expected), Ok(answer)) if answer > expected => {
+                "That's not the right answer; your answer is too high."
+            }
+            (Ok(expected), Ok(answer)) if answer < expected => {
+                "That's not the right answer; your answer is too low."
+            }
+            _ => "That's not the right answer.",
+        }
+    }
+}
+
+fn read_dir(dir: &Path) -> Result<impl Iterator<Item = fs::DirEntry>, BackendError> {
+    let entries = fs::read_dir(dir).map_err(|e| {
+        BackendError::Unavailable(format!("can not read \"{}\": {e}", dir.display()))
+    })?;
+
+    Ok(entries.filter_map(Result::ok))
+}
+
+impl PuzzleBackend for FakeBackend {
+    fn name(&self) -> &'static str {
+        "the fake backend"
+    }
+
+    fn input(&self, id: PuzzleId) -> Result<String, BackendError> {
+        self.inputs.get(&id).cloned().ok_or_else(|| {
+            BackendError::Failed(format!("no input for day {} of {}.", id.day, id.year))
+        })
+    }
+
+    fn description(&self, id: PuzzleId) -> Result<String, BackendError> {
+        self.descriptions
+            .get(&id)
+            .cloned()
+            .or_else(|| self.pages.get(&id).map(|page| puzzle::to_markdown(page)))
+            .ok_or_else(|| {
+                BackendError::Failed(format!("no description for day {} of {}.", id.day, id.year))
+            })
+    }
+
+    fn page(&self, id: PuzzleId) -> Result<String, BackendError> {
+        self.pages.get(&id).cloned().ok_or_else(|| {
+            // NOTE: a day served from `puzzle.md` is like aoc-cli, it only has a description.
+            if self.descriptions.contains_key(&id) {
+                return BackendError::Unavailable(format!(
+                    "no page for day {} of {}, only a description.",
+                    id.day, id.year
+                ));
+            }
+            BackendError::Failed(format!("no page for day {} of {}.", id.day, id.year))
+        })
+    }
+
+    fn submit(&self, id: PuzzleId, part: u8, answer: &str) -> Result<String, BackendError> {
+        self.submissions
+            .borrow_mut()
+            .push((id, part, answer.to_string()));
+
+        Ok(self.judge(id, part, answer).to_string())
+    }
+
+    fn leaderboard(&self, year: Year, id: &str) -> Result<String, BackendError> {
+        self.leaderboards
+            .get(&(year, id.to_string()))
+            .cloned()
+            .ok_or_else(|| BackendError::Failed(format!("no leaderboard with id {id} in {year}.")))
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        BackendError, Cached, DEFAULT_REQUEST_DELAY, FakeBackend, PuzzleBackend, Throttled,
+        account_dir, parse_request_delay, select, submit,
+    };
+    use crate::{
+        day,
+        template::{
+            Day, PuzzleId,
+            answers::Answers,
+            aoc_cli::{Hint, SubmitOutcome},
+        },
+        year,
+    };
+    use std::{
+        env, fs,
+        path::Path,
+        time::{Duration, Instant},
+    };
+
+    fn puzzle(day: Day) -> PuzzleId {
+        PuzzleId::new(year!(2025), day)
+    }
+
+    fn get_fake_backend() -> FakeBackend {
+        let mut backend = FakeBackend::default();
+        backend.inputs.insert(puzzle(day!(1)), "1\n2\n".into());
+        backend.answers.insert(
+            puzzle(day!(1)),
+            Answers {
+                part_1: Some("42".into()),
+                part_2: Some("ABC".into()),
+            },
+        );
+        backend
+    }
+
+    #[test]
+    fn judges_answers() {
+        let backend = get_fake_backend();
+
+        assert_eq!(
+            submit(&backend, puzzle(day!(1)), 1, "50").unwrap(),
+            SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooHigh)
+            }
+        );
+        assert_eq!(
+            submit(&backend, puzzle(day!(1)), 1, "7").unwrap(),
+            SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooLow)
+            }
+        );
+        assert_eq!(
+            submit(&backend, puzzle(day!(1)), 1, "42").unwrap(),
+            SubmitOutcome::Correct
+        );
+        assert_eq!(
+            submit(&backend, puzzle(day!(1)), 2, "ABD").unwrap(),
+            SubmitOutcome::Incorrect { hint: None }
+        );
+        assert_eq!(backend.submissions.borrow().len(), 4);
+    }
+
+    #[test]
+    fn locks_parts_like_the_server() {
+        let backend = get_fake_backend();
+
+        assert_eq!(
+            submit(&backend, puzzle(day!(1)), 2, "ABC").unwrap(),
+            SubmitOutcome::WrongLevel
+        );
+
+        submit(&backend, puzzle(day!(1)), 1, "42").unwrap();
+        assert_eq!(
+            submit(&backend, puzzle(day!(1)), 1, "42").unwrap(),
+            SubmitOutcome::AlreadySolved
+        );
+        assert_eq!(
+            submit(&backend, puzzle(day!(1)), 2, "ABC").unwrap(),
+            SubmitOutcome::Correct
+        );
+    }
+
+    #[test]
+    fn serves_puzzles() {
+        let backend = get_fake_backend();
+
+        assert_eq!(backend.input(puzzle(day!(1))).unwrap(), "1\n2\n");
+        assert_eq!(
+            matches!(backend.input(puzzle(day!(2))), Err(BackendError::Failed(_))),
+            true
+        );
+        assert_eq!(backend.description(puzzle(day!(1))).is_err(), true);
+    }
+
+    #[test]
+    fn loads_puzzles_from_dir() {
+        let dir = env::temp_dir().join(format!("aoc_fake_backend_{}", std::process::id()));
+        fs::create_dir_all(dir.join("2025/day_03")).unwrap();
+        fs::write(dir.join("2025/day_03/input.txt"), "input").unwrap();
+        fs::write(dir.join("2025/day_03/puzzle.md"), "--- Day 3 ---").unwrap();
+        fs::write(dir.join("2025/leaderboard_42.json"), "{}").unwrap();
+        fs::write(
+            dir.join("2025/day_03/answers.json"),
+            r#"{ "part_1": "7", "part_2": null }"#,
+        )
+        .unwrap();
+
+        let backend = FakeBackend::from_dir(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+        let backend = backend.unwrap();
+
+        assert_eq!(backend.input(puzzle(day!(3))).unwrap(), "input");
+        assert_eq!(
+            backend.description(puzzle(day!(3))).unwrap(),
+            "--- Day 3 ---"
+        );
+        assert_eq!(
+            matches!(
+                backend.page(puzzle(day!(3))),
+                Err(BackendError::Unavailable(_))
+            ),
+            true
+        );
+        assert_eq!(backend.leaderboard(year!(2025), "42").unwrap(), "{}");
+        assert_eq!(
+            backend.input(PuzzleId::new(year!(2024), day!(3))).is_err(),
+            true
+        );
+        assert_eq!(
+            submit(&backend, puzzle(day!(3)), 1, "7").unwrap(),
+            SubmitOutcome::Correct
+        );
+    }
+
+    #[test]
+    fn caches_inputs_by_year() {
+        let dir = env::temp_dir().join(format!("aoc_input_cache_{}", std::process::id()));
+
+        let backend = Cached::new(Box::new(get_fake_backend()), dir.clone());
+        assert_eq!(backend.input(puzzle(day!(1))).unwrap(), "1\n2\n");
+
+        let offline = Cached::new(Box::new(FakeBackend::default()), dir.clone());
+        let cached = offline.input(puzzle(day!(1)));
+        let uncached = offline.input(PuzzleId::new(year!(2024), day!(1)));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(cached.unwrap(), "1\n2\n");
+        assert_eq!(uncached.is_err(), true);
+    }
+
+    #[test]
+    fn keys_cache_by_account() {
+        let dir = Path::new("cache");
+
+        assert_eq!(account_dir(dir, "abc"), account_dir(dir, "abc\n"));
+        assert_eq!(account_dir(dir, "abc") == account_dir(dir, "abd"), false);
+        assert_eq!(
+            account_dir(dir, "abc").to_string_lossy().contains("abc"),
+            false
+        );
+    }
+
+    #[test]
+    fn throttles_requests() {
+        let backend = Throttled::new(Box::new(get_fake_backend()), Duration::from_millis(50));
+
+        let start = Instant::now();
+        backend.input(puzzle(day!(1))).unwrap();
+        assert_eq!(start.elapsed() < Duration::from_millis(50), true);
+
+        backend.input(puzzle(day!(1))).unwrap();
+        backend.input(puzzle(day!(1))).unwrap();
+        assert_eq!(start.elapsed() >= Duration::from_millis(100), true);
+    }
+
+    #[test]
+    fn parses_request_delays() {
+        assert_eq!(parse_request_delay(None).unwrap(), DEFAULT_REQUEST_DELAY);
+        assert_eq!(
+            parse_request_delay(Some("250")).unwrap(),
+            Duration::from_millis(250)
+        );
+        assert_eq!(
+            parse_request_delay(Some("1s")).err().unwrap().to_string(),
+            "invalid value for AOC_REQUEST_DELAY: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_backends() {
+        assert_eq!(
+            select(Some("carrier-pigeon"), None)
+                .err()
+                .unwrap()
+                .to_string(),
+            "unknown backend `carrier-pigeon`, expecting one of aoc-cli, native or fake."
+        );
+    }
+}
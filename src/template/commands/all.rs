@@ -1,5 +1,5 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{RunOptions, Year, run_multi::run_multi};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(year: Year, options: &RunOptions) {
+    run_multi(year, &year.days().collect(), options);
 }
@@ -1,28 +1,170 @@
 use crate::{
-    day_path,
-    template::{Day, aoc_cli},
+    day_path, examples_path, input_path, puzzle_path,
+    template::{
+        Day, PuzzleId, Year,
+        backend::{self, BackendError, PuzzleBackend},
+        examples::Example,
+        puzzle, unlocked_days,
+    },
 };
-use std::{fs, process};
+use std::{fs, path::Path, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+pub fn handle(id: PuzzleId) {
+    let backend = get_backend(backend::from_env());
+
+    if let Err(e) = download(backend.as_ref(), id) {
+        eprintln!("{e}");
         process::exit(1);
     }
+}
+
+/// Downloads every unlocked day of `year` that does not have an input yet.
+pub fn handle_all(year: Year) {
+    let backend = get_backend(backend::throttled_from_env());
 
-    let day_path = format!(day_path!(), day);
-    match fs::create_dir_all(day_path) {
-        Ok(()) => (),
-        Err(e) => {
-            eprintln!("Failed to create day folder: {e}");
-            process::exit(1);
+    let mut fetched = vec![];
+    let mut skipped = vec![];
+    let mut failed = vec![];
+
+    for day in unlocked_days(year) {
+        let id = PuzzleId::new(year, day);
+
+        if has_input(id) {
+            skipped.push(day);
+            continue;
         }
-    };
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+        match download(backend.as_ref(), id) {
+            Ok(()) => fetched.push(day),
+            Err(e) => {
+                eprintln!("{e}");
+                failed.push(day);
+            }
+        }
+    }
+
+    println!("---");
+    print_days("Fetched", &fetched);
+    print_days("Skipped", &skipped);
+    print_days("Failed", &failed);
+
+    if !failed.is_empty() {
         process::exit(1);
-    };
+    }
+}
+
+fn get_backend(backend: Result<Box<dyn PuzzleBackend>, BackendError>) -> Box<dyn PuzzleBackend> {
+    backend.unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    })
+}
+
+fn has_input(id: PuzzleId) -> bool {
+    fs::read_to_string(format!(input_path!(), id.year, id.day))
+        .is_ok_and(|input| !input.trim().is_empty())
+}
+
+fn print_days(label: &str, days: &[Day]) {
+    let days: Vec<String> = days.iter().map(Day::to_string).collect();
+    let noun = if days.len() == 1 { "day" } else { "days" };
+
+    if days.is_empty() {
+        println!("🎄 {label} 0 days.");
+    } else {
+        println!("🎄 {label} {} {noun}: {}.", days.len(), days.join(", "));
+    }
+}
+
+/// Downloads the input, description and examples of a day.
+fn download(backend: &dyn PuzzleBackend, id: PuzzleId) -> Result<(), String> {
+    let day_path = format!(day_path!(), id.year, id.day);
+    if let Err(e) = fs::create_dir_all(day_path) {
+        return Err(format!("Failed to create day folder: {e}"));
+    }
+
+    let input_path = format!(input_path!(), id.year, id.day);
+    let puzzle_path = format!(puzzle_path!(), id.year, id.day);
+
+    // NOTE: aoc-cli does not hand out the page, examples are only extracted by the other backends.
+    // Any other failure to fetch the page fails the download, like a failure to fetch the input.
+    let result = backend
+        .page(id)
+        .map(Some)
+        .or_else(|e| match e {
+            BackendError::Unavailable(_) => Ok(None),
+            e => Err(e),
+        })
+        .and_then(|page| {
+            let input = backend.input(id)?;
+            let description = match &page {
+                Some(page) => puzzle::to_markdown(page),
+                None => backend.description(id)?,
+            };
+            Ok((page, input, description))
+        });
+
+    let (page, input, description) =
+        result.map_err(|e| format!("Failed to download day {} of {}: {e}", id.day, id.year))?;
+
+    fs::write(&input_path, input).map_err(|e| format!("Failed to write input: {e}"))?;
+    fs::write(&puzzle_path, description).map_err(|e| format!("Failed to write puzzle: {e}"))?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+
+    if let Some(page) = page {
+        store_examples(id, &puzzle::extract_examples(&page));
+    }
+
+    Ok(())
+}
+
+/// Writes extracted examples. Examples that were already written keep their content,
+/// only the answers that were unlocked since are added to their expectations.
+fn store_examples(id: PuzzleId, examples: &[Example]) {
+    let examples_path = format!(examples_path!(), id.year, id.day);
+
+    if let Err(e) = fs::create_dir_all(&examples_path) {
+        eprintln!("Failed to create examples folder: {e}");
+        return;
+    }
+
+    for example in examples {
+        let path = Path::new(&examples_path).join(&example.name);
+
+        let existing = Example::read(id, &example.name)
+            .ok()
+            .filter(|existing| !existing.input.trim().is_empty());
+
+        let (example, message) = match existing {
+            None => (example.clone(), "Extracted example to"),
+            Some(existing) if existing.input.trim() != example.input.trim() => {
+                println!("🎄 Kept existing example \"{}\".", path.display());
+                continue;
+            }
+            Some(mut existing) => {
+                let mut is_updated = false;
+                for part in [1, 2] {
+                    if let (None, Some(answer)) =
+                        (existing.expected.get(part), example.expected.get(part))
+                    {
+                        existing.expected.set(part, answer.clone());
+                        is_updated = true;
+                    }
+                }
+
+                if !is_updated {
+                    continue;
+                }
+                (existing, "Updated expectations of")
+            }
+        };
+
+        match example.store(id) {
+            Ok(()) => println!("🎄 {message} \"{}\".", path.display()),
+            Err(e) => eprintln!("Failed to write example \"{}\": {e}", path.display()),
+        }
+    }
 }
@@ -0,0 +1,49 @@
+use std::{process, str::FromStr};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, Year, backend,
+    leaderboard::{self, Leaderboard},
+};
+
+/// Shows the standings of a private leaderboard, read from `file` or fetched by `id` for `year`.
+pub fn handle(year: Year, id: Option<&str>, file: Option<&str>, store: bool) {
+    let result = match (file, id) {
+        (Some(file), _) => Leaderboard::read(file),
+        (None, Some(id)) => fetch(year, id),
+        (None, None) => Err("expecting a leaderboard id or a file.".into()),
+    };
+
+    let leaderboard = result.unwrap_or_else(|e| {
+        eprintln!("Failed to read leaderboard: {e}");
+        process::exit(1);
+    });
+
+    println!(
+        "{ANSI_BOLD}{:>4} {:<32} {:>6} {:>6}{ANSI_RESET}",
+        "#", "Member", "Score", "Stars"
+    );
+
+    for (rank, member) in leaderboard.members.iter().enumerate() {
+        println!(
+            "{:>4} {:<32} {:>6} {:>6}",
+            rank + 1,
+            member.display_name(),
+            member.local_score,
+            member.stars()
+        );
+    }
+
+    if store {
+        println!();
+        match leaderboard::update(&leaderboard) {
+            Ok(()) => println!("Stored the leaderboard in the readme."),
+            Err(_) => eprintln!("Failed to store the leaderboard in the readme."),
+        }
+    }
+}
+
+fn fetch(year: Year, id: &str) -> Result<Leaderboard, String> {
+    let backend = backend::from_env().map_err(|e| e.to_string())?;
+    let json = backend.leaderboard(year, id).map_err(|e| e.to_string())?;
+    Leaderboard::from_str(&json)
+}
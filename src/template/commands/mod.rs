@@ -1,8 +1,10 @@
 pub mod all;
 pub mod download;
+pub mod leaderboard;
 #[macro_use]
 pub mod paths;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
 pub mod time;
+pub mod verify;
@@ -1,28 +1,28 @@
 #[macro_export]
 macro_rules! day_path {
     () => {
-        "data/day_{0}"
+        "data/{0}/day_{1}"
     };
 }
 
 #[macro_export]
 macro_rules! input_path {
     () => {
-        "data/day_{0}/input.txt"
+        "data/{0}/day_{1}/input.txt"
     };
 }
 
 #[macro_export]
 macro_rules! examples_path {
     () => {
-        "data/day_{0}/examples"
+        "data/{0}/day_{1}/examples"
     };
 }
 
 #[macro_export]
 macro_rules! example_path {
     () => {
-        "data/day_{0}/examples/example_1.txt"
+        "data/{0}/day_{1}/examples/example_1.txt"
     };
 }
 
@@ -36,6 +36,34 @@ macro_rules! module_path {
 #[macro_export]
 macro_rules! puzzle_path {
     () => {
-        "data/day_{0}/puzzle.md"
+        "data/{0}/day_{1}/puzzle.md"
+    };
+}
+
+#[macro_export]
+macro_rules! registry_path {
+    () => {
+        "src/bin/all.rs"
+    };
+}
+
+#[macro_export]
+macro_rules! answers_path {
+    () => {
+        "data/{0}/day_{1}/answers.json"
+    };
+}
+
+#[macro_export]
+macro_rules! submissions_path {
+    () => {
+        "data/{0}/day_{1}/submissions.json"
+    };
+}
+
+#[macro_export]
+macro_rules! example_answers_path {
+    () => {
+        "data/{0}/day_{1}/examples/example_1.json"
     };
 }
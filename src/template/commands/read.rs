@@ -1,17 +1,40 @@
-use std::process;
+use std::{fs, process};
 
-use crate::template::{Day, aoc_cli};
+use crate::template::{PuzzleId, answers::Answers, backend, puzzle};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+pub fn handle(id: PuzzleId) {
+    let backend = backend::from_env().unwrap_or_else(|e| {
+        eprintln!("{e}");
         process::exit(1);
+    });
+
+    let puzzle_path = format!(puzzle_path!(), id.year, id.day);
+    // A missing answers file reads as no answers, only a malformed one is an error.
+    let answers = Answers::read(id).unwrap_or_else(|e| {
+        eprintln!("Failed to read answers: {e}");
+        Answers::default()
+    });
+
+    if let Ok(local) = fs::read_to_string(&puzzle_path)
+        && puzzle::is_stale(&local, &answers)
+    {
+        println!(
+            "🎄 \"{puzzle_path}\" is stale: part 1 is solved, but it does not show part 2 yet. Run `cargo download {}` to refresh it.",
+            id.day
+        );
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    let description = match backend.description(id) {
+        Ok(description) => description,
+        Err(e) => {
+            eprintln!("Failed to read day {} of {}: {e}", id.day, id.year);
+            process::exit(1);
+        }
     };
+
+    println!("{}", description.trim_end());
+
+    if puzzle::is_stale(&description, &answers) {
+        eprintln!("The fetched puzzle does not show part 2 either, even though part 1 is solved.");
+    }
 }
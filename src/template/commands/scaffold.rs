@@ -3,13 +3,57 @@ use std::{
     fs::{File, OpenOptions},
     io::Write,
     process,
+    str::FromStr,
 };
 
-use crate::template::Day;
+use crate::template::PuzzleId;
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
+const EXAMPLE_ANSWERS_TEMPLATE: &str = "{ \"part_1\": null, \"part_2\": null, \"params\": {} }\n";
+
+/// Answer type of the scaffolded `part_one` and `part_two` functions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnswerType {
+    #[default]
+    U64,
+    I64,
+    U128,
+    String,
+    /// Letters drawn on a grid, see `advent_stdlib::Matrix`.
+    Grid,
+}
+
+impl AnswerType {
+    fn rust_type(self) -> &'static str {
+        match self {
+            AnswerType::U64 => "u64",
+            AnswerType::I64 => "i64",
+            AnswerType::U128 => "u128",
+            AnswerType::String => "String",
+            AnswerType::Grid => "advent_of_code::advent_stdlib::Matrix<char>",
+        }
+    }
+}
+
+impl FromStr for AnswerType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "u64" => Ok(AnswerType::U64),
+            "i64" => Ok(AnswerType::I64),
+            "u128" => Ok(AnswerType::U128),
+            "string" => Ok(AnswerType::String),
+            "grid" => Ok(AnswerType::Grid),
+            x => Err(format!(
+                "unknown answer type `{x}`, expecting one of u64, i64, u128, string or grid."
+            )),
+        }
+    }
+}
+
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
@@ -28,13 +72,38 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let day_path = format!(day_path!(), day);
-    let examples_path = format!(examples_path!(), day);
+/// Adds the day to the `registry!` list of the bundled binary, unless it is already present.
+fn register_day(id: PuzzleId) -> Result<bool, std::io::Error> {
+    let registry_path = registry_path!();
+    let registry = fs::read_to_string(registry_path)?;
 
-    let input_path = format!(input_path!(), day);
-    let example_path = format!(example_path!(), day);
-    let module_path = format!(module_path!(), day);
+    let entry_path = format!("\"{id}.rs\"");
+    if registry.contains(&entry_path) {
+        return Ok(false);
+    }
+
+    let Some(end) = registry.rfind('}') else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "could not find the end of the registry! invocation",
+        ));
+    };
+
+    let mut updated = registry;
+    updated.insert_str(end, &format!("    day_{id} => {entry_path},\n"));
+    fs::write(registry_path, updated)?;
+    Ok(true)
+}
+
+pub fn handle(id: PuzzleId, overwrite: bool, answer_type: AnswerType) {
+    let PuzzleId { year, day } = id;
+    let day_path = format!(day_path!(), year, day);
+    let examples_path = format!(examples_path!(), year, day);
+
+    let input_path = format!(input_path!(), year, day);
+    let example_path = format!(example_path!(), year, day);
+    let example_answers_path = format!(example_answers_path!(), year, day);
+    let module_path = format!(module_path!(), id);
 
     match fs::create_dir_all(day_path) {
         Ok(()) => (),
@@ -55,6 +124,7 @@ pub fn handle(day: Day, overwrite: bool) {
     match file.write_all(
         MODULE_TEMPLATE
             .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+            .replace("%ANSWER_TYPE%", answer_type.rust_type())
             .as_bytes(),
     ) {
         Ok(()) => {
@@ -66,6 +136,16 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
+    match register_day(id) {
+        Ok(true) => {
+            println!("Registered day in \"{}\"", registry_path!());
+        }
+        Ok(false) => (),
+        Err(e) => {
+            eprintln!("Failed to register day in \"{}\": {e}", registry_path!());
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
             println!("Created empty input file \"{}\"", &input_path);
@@ -94,6 +174,16 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
+    match fs::write(&example_answers_path, EXAMPLE_ANSWERS_TEMPLATE) {
+        Ok(()) => {
+            println!("Created example answers file \"{}\"", &example_answers_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to create example answers file: {e}");
+            process::exit(1);
+        }
+    }
+
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
 }
@@ -1,9 +1,17 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
-use crate::template::Day;
+use crate::template::PuzzleId;
+use crate::template::input::INPUT_FLAG;
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(
+    id: PuzzleId,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    examples: bool,
+    input: Option<&str>,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), id.to_string()];
 
     if dhat {
         cmd_args.extend([
@@ -16,6 +24,11 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push("--release".to_string());
     }
 
+    // NOTE: solutions submit their answers themselves, with the client this command was built with.
+    if cfg!(feature = "native_client") && submit_part.is_some() {
+        cmd_args.extend(["--features".to_string(), "native_client".to_string()]);
+    }
+
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
@@ -23,6 +36,15 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if examples {
+        cmd_args.push("--examples".to_string());
+    }
+
+    if let Some(input) = input {
+        cmd_args.push(INPUT_FLAG.to_string());
+        cmd_args.push(input.to_string());
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -30,5 +52,9 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    let status = cmd.wait().unwrap();
+
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
 }
@@ -1,19 +1,38 @@
 use std::collections::HashSet;
+use std::process;
 
-use crate::template::run_multi::run_multi;
+use crate::template::records::PartRecord;
+use crate::template::run_multi::{run_multi, run_variants, timing_from_records};
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{Day, PuzzleId, RunOptions, Year, readme_benchmarks, regression, variants};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Benches the requested days of `year`. With `check` set to a threshold in percent, the fresh timings are
+/// compared with the stored ones instead, and the process exits non-zero if any part regressed.
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    check: Option<f64>,
+    options: &RunOptions,
+) {
+    let stored_timings = Timings::read_from_file(year);
+
+    if check.is_some() && stored_timings.data.is_empty() {
+        eprintln!("No stored timings to check against, run `cargo time --all --store` first.");
+        process::exit(1);
+    }
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
-                all_days().collect()
+            if check.is_some() {
+                // a check only makes sense for days that have a baseline.
+                stored_timings.data.iter().map(|t| t.day).collect()
+            } else if run_all {
+                year.days().collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
+                year.days()
                     .filter(|day| !stored_timings.is_day_complete(*day))
                     .collect()
             }
@@ -21,20 +40,84 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let options = RunOptions {
+        is_release: true,
+        is_timed: true,
+        ..options.clone()
+    };
 
-    if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+    let timings = run_multi(year, &days_to_run, &options).unwrap();
+
+    if let Some(threshold) = check {
+        let deltas = regression::compare(&stored_timings, &timings);
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
-                println!("Stored updated benchmarks.");
-            }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
-            }
+        let regressions = regression::print_report(&deltas, threshold);
+
+        println!();
+        if regressions > 0 {
+            eprintln!("{regressions} part(s) regressed by more than {threshold}%.");
+            process::exit(1);
+        }
+
+        println!("No regressions above {threshold}%.");
+        return;
+    }
+
+    if store {
+        store_timings(year, stored_timings.merge(&timings));
+    }
+}
+
+/// Benches every variant of a day side by side. With `store` set, the fastest variant of
+/// every part replaces the stored timing of that part.
+pub fn handle_variants(id: PuzzleId, store: bool, options: &RunOptions) {
+    let options = RunOptions {
+        is_release: true,
+        is_timed: true,
+        ..options.clone()
+    };
+
+    let records = run_variants(id, &options);
+
+    if records.is_empty() {
+        eprintln!(
+            "Day {} has no variants, register them with `variants!` first.",
+            id.day
+        );
+        process::exit(1);
+    }
+
+    println!();
+    variants::print_report(&records);
+
+    if store {
+        let winners: Vec<PartRecord> = variants::winners(&records).into_iter().cloned().collect();
+        let fresh = timing_from_records(&winners, id.day);
+
+        let stored_timings = Timings::read_from_file(id.year);
+        let timing = match stored_timings.data.iter().find(|t| t.day == id.day) {
+            Some(stored) => stored.merge_phases(&fresh),
+            None => fresh,
+        };
+
+        store_timings(
+            id.year,
+            stored_timings.merge(&Timings { data: vec![timing] }),
+        );
+    }
+}
+
+fn store_timings(year: Year, timings: Timings) {
+    timings.store_file(year).unwrap();
+
+    println!();
+    match readme_benchmarks::update(year, timings) {
+        Ok(()) => {
+            println!("Stored updated benchmarks.");
+        }
+        Err(_) => {
+            eprintln!("Failed to store updated benchmarks.");
         }
     }
 }
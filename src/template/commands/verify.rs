@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+use std::process;
+
+use crate::template::answers::{self, Answers, shorten};
+use crate::template::records::PartStatus;
+use crate::template::run_multi::{DayRun, run_days};
+use crate::template::{
+    ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET, Day, PuzzleId, RunOptions, Year,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Verdict {
+    Correct,
+    Wrong,
+    /// The part has a recorded answer, but the solution did not produce one.
+    Missing,
+    /// The part has no recorded answer to compare with.
+    Unrecorded,
+}
+
+impl Verdict {
+    fn is_failure(self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::Missing)
+    }
+
+    fn format(self) -> String {
+        match self {
+            Verdict::Correct => format!("{ANSI_GREEN}✔ correct{ANSI_RESET}"),
+            Verdict::Wrong => format!("{ANSI_RED}✖ wrong{ANSI_RESET}"),
+            Verdict::Missing => format!("{ANSI_RED}✖ missing{ANSI_RESET}"),
+            Verdict::Unrecorded => "- no answer".into(),
+        }
+    }
+}
+
+/// Runs the requested days of `year` and compares their output with the answers in
+/// `data/<year>/day_XX/answers.json`. Without a day or `--all`, only days with recorded answers are verified.
+pub fn handle(year: Year, day: Option<Day>, run_all: bool, options: &RunOptions) {
+    let days_to_run: HashSet<Day> = day.map_or_else(
+        || {
+            year.days()
+                .filter(|day| run_all || !read_answers(PuzzleId::new(year, *day)).is_empty())
+                .collect()
+        },
+        |day| HashSet::from([day]),
+    );
+
+    if days_to_run.is_empty() {
+        eprintln!(
+            "No recorded answers to verify, submit a solution or add `data/{year}/day_XX/answers.json`."
+        );
+        process::exit(1);
+    }
+
+    let options = RunOptions {
+        is_release: true,
+        is_timed: false,
+        ..options.clone()
+    };
+
+    let runs = run_days(year, &days_to_run, &options);
+
+    let mut days: Vec<Day> = days_to_run.into_iter().collect();
+    days.sort_unstable();
+
+    println!();
+    println!(
+        "{ANSI_BOLD}{:<5} {:<8} {:<20} {:<20} Status{ANSI_RESET}",
+        "Day", "Part", "Expected", "Actual"
+    );
+
+    let mut failures = 0;
+
+    for day in days {
+        let answers = read_answers(PuzzleId::new(year, day));
+        let run = runs.iter().find(|run| run.day == day);
+
+        for part in [1, 2] {
+            let expected = answers.get(part).map(String::as_str);
+            let actual = get_answer(run, part);
+
+            let verdict = match (expected, actual) {
+                (None, _) => Verdict::Unrecorded,
+                (Some(_), None) => Verdict::Missing,
+                (Some(expected), Some(actual)) if answers::matches(expected, actual) => {
+                    Verdict::Correct
+                }
+                (Some(_), Some(_)) => Verdict::Wrong,
+            };
+
+            if verdict.is_failure() {
+                failures += 1;
+            }
+
+            println!(
+                "{:<5} {:<8} {:<20} {:<20} {}",
+                day.to_string(),
+                format!("Part {part}"),
+                shorten(expected),
+                shorten(actual),
+                verdict.format()
+            );
+        }
+    }
+
+    println!();
+    if failures > 0 {
+        eprintln!("{failures} part(s) did not match their recorded answer.");
+        process::exit(1);
+    }
+
+    println!("All recorded answers match.");
+}
+
+fn read_answers(id: PuzzleId) -> Answers {
+    Answers::read(id).unwrap_or_else(|e| {
+        eprintln!("Failed to read answers: {e}");
+        Answers::default()
+    })
+}
+
+fn get_answer(run: Option<&DayRun>, part: u8) -> Option<&str> {
+    run?.records
+        .iter()
+        .find(|r| r.part == part && r.status == PartStatus::Solved)
+        .and_then(|r| r.answer.as_deref())
+}
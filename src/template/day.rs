@@ -1,6 +1,9 @@
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::template::Year;
 
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
@@ -8,6 +11,9 @@ use chrono::{Datelike, FixedOffset, Utc};
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
+/// Puzzles unlock at midnight on the server, which runs on EST (UTC-5).
+const UNLOCK_HOUR_UTC: u64 = 5;
+
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
 ///
 /// # Display
@@ -35,6 +41,27 @@ impl Day {
     pub fn into_inner(self) -> u8 {
         self.0
     }
+
+    /// The moment the puzzle of this day unlocks in `year`.
+    pub fn unlocks_at(self, year: Year) -> SystemTime {
+        let days = days_from_civil(year.into_inner().into(), 12, self.0.into());
+        UNIX_EPOCH + Duration::from_secs(days * 86_400 + UNLOCK_HOUR_UTC * 3_600)
+    }
+
+    /// Whether the puzzle of this day was unlocked in `year`.
+    pub fn is_unlocked(self, year: Year) -> bool {
+        SystemTime::now() >= self.unlocks_at(year)
+    }
+}
+
+/// Days between the unix epoch and a date after it.
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let year_of_era = year % 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    (year / 400) * 146_097 + day_of_era - 719_468
 }
 
 #[cfg(feature = "today")]
@@ -126,6 +153,11 @@ impl Iterator for AllDays {
     }
 }
 
+/// Every day of advent that was unlocked in `year`.
+pub fn unlocked_days(year: Year) -> impl Iterator<Item = Day> {
+    year.days().take_while(move |day| day.is_unlocked(year))
+}
+
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
@@ -143,7 +175,9 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{Day, all_days};
+    use super::{Day, all_days, unlocked_days};
+    use crate::year;
+    use std::time::{Duration, UNIX_EPOCH};
 
     #[test]
     fn all_days_iterator() {
@@ -176,6 +210,25 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn unlocks_at_midnight_est() {
+        assert_eq!(
+            Day(1).unlocks_at(year!(2025)),
+            UNIX_EPOCH + Duration::from_secs(1_764_565_200)
+        );
+        assert_eq!(
+            Day(25).unlocks_at(year!(2016)),
+            UNIX_EPOCH + Duration::from_secs(1_482_642_000)
+        );
+    }
+
+    #[test]
+    fn unlocked_days_iterator() {
+        assert_eq!(unlocked_days(year!(2015)).count(), 25);
+        assert_eq!(unlocked_days(year!(2025)).count(), 12);
+        assert_eq!(unlocked_days(year!(9999)).count(), 0);
+    }
 }
 
 /* -------------------------------------------------------------------------- */
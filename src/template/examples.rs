@@ -0,0 +1,361 @@
+/// Example inputs of a day and their expected answers.
+/// Every `data/<year>/day_XX/examples/<name>.txt` may sit next to a `<name>.json` with the expected
+/// answers and the puzzle parameters that differ from the real input:
+/// `{ "part_1": "40", "part_2": "25272", "params": { "connections": 10 } }`.
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::examples_path;
+use crate::template::answers::{self, Answers, shorten};
+use crate::template::records::{PartRecord, PartStatus};
+use crate::template::registry::Solution;
+use crate::template::{ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET, PuzzleId};
+
+thread_local! {
+    static CURRENT: RefCell<Option<Example>> = const { RefCell::new(None) };
+}
+
+/// Reads a puzzle parameter of the example that currently runs, e.g. a grid size that is
+/// smaller for the example than for the real input. Yields `None` for the real input.
+#[must_use]
+pub fn param<T: FromStr>(name: &str) -> Option<T> {
+    CURRENT.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .and_then(|example| example.params.get(name))
+            .and_then(|x| x.parse().ok())
+    })
+}
+
+/// Whether an example runs instead of the real input.
+#[must_use]
+pub fn is_running() -> bool {
+    CURRENT.with(|current| current.borrow().is_some())
+}
+
+/// Whether `part` is left out of the example that currently runs.
+/// Examples with expected answers only run the parts they have an answer for,
+/// as examples for one part are often not valid input for the other one.
+#[must_use]
+pub fn skips_part(part: u8) -> bool {
+    CURRENT.with(|current| {
+        current.borrow().as_ref().is_some_and(|example| {
+            !example.expected.is_empty() && example.expected.get(part).is_none()
+        })
+    })
+}
+
+/// An example input with its expected answers.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    /// File name of the example, e.g. `example_1.txt`.
+    pub name: String,
+    pub input: String,
+    pub expected: Answers,
+    pub params: HashMap<String, String>,
+}
+
+impl Example {
+    /// Reads the example `name` of a puzzle along with its expectations, if any.
+    pub fn read(id: PuzzleId, name: &str) -> Result<Self, String> {
+        let path = Path::new(&format!(examples_path!(), id.year, id.day)).join(name);
+        let input = fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+
+        let expectations_path = path.with_extension("json");
+        let (expected, params) = if expectations_path.exists() {
+            let content = fs::read_to_string(&expectations_path).map_err(|e| e.to_string())?;
+            parse_expectations(&content)
+                .map_err(|e| format!("{}: {e}", expectations_path.display()))?
+        } else {
+            (Answers::default(), HashMap::new())
+        };
+
+        Ok(Example {
+            name: name.to_string(),
+            input,
+            expected,
+            params,
+        })
+    }
+
+    /// Reads all examples of a puzzle, ordered by file name.
+    pub fn read_all(id: PuzzleId) -> Result<Vec<Self>, String> {
+        let mut files: Vec<PathBuf> = fs::read_dir(format!(examples_path!(), id.year, id.day))
+            .map_err(|e| e.to_string())?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+            .collect();
+        files.sort();
+
+        files
+            .iter()
+            .filter_map(|path| path.file_name())
+            .map(|name| Example::read(id, &name.to_string_lossy()))
+            .collect()
+    }
+
+    /// Writes the example to `data/<year>/day_XX/examples`, along with its expectations.
+    pub fn store(&self, id: PuzzleId) -> Result<(), io::Error> {
+        let path = Path::new(&format!(examples_path!(), id.year, id.day)).join(&self.name);
+        fs::write(&path, &self.input)?;
+
+        let mut expectations = match JsonValue::from(&self.expected) {
+            JsonValue::Object(map) => map,
+            _ => HashMap::new(),
+        };
+        let params = self
+            .params
+            .iter()
+            .map(|(key, value)| (key.clone(), JsonValue::String(value.clone())))
+            .collect();
+        expectations.insert("params".into(), JsonValue::Object(params));
+
+        let mut file = fs::File::create(path.with_extension("json"))?;
+        JsonValue::Object(expectations).format_to(&mut file)
+    }
+
+    /// Runs `solution` on the example with its parameters in place.
+    pub fn run(&self, solution: Solution) -> Vec<PartRecord> {
+        self.with(|input| (solution.solve)(input))
+    }
+
+    /// Calls `f` with the input of the example while its parameters are in place.
+    pub fn with<T>(&self, f: impl FnOnce(&str) -> T) -> T {
+        CURRENT.with(|current| current.replace(Some(self.clone())));
+        let result = f(&self.input);
+        CURRENT.with(|current| current.take());
+        result
+    }
+}
+
+/// The answer of a part compared with its expectation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartCheck {
+    pub part: u8,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+}
+
+impl PartCheck {
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        match (&self.expected, &self.actual) {
+            (None, _) => true,
+            (Some(expected), Some(actual)) => answers::matches(expected, actual),
+            (Some(_), None) => false,
+        }
+    }
+}
+
+/// Compares the records of a run with the expected answers of both parts.
+#[must_use]
+pub fn check(expected: &Answers, records: &[PartRecord]) -> Vec<PartCheck> {
+    [1, 2]
+        .into_iter()
+        .map(|part| PartCheck {
+            part,
+            expected: expected.get(part).cloned(),
+            actual: records
+                .iter()
+                .find(|r| r.part == part && r.status == PartStatus::Solved)
+                .and_then(|r| r.answer.clone()),
+        })
+        .collect()
+}
+
+/// Entry point of the tests generated by `example_tests!`.
+/// Examples without expected answers are skipped.
+pub fn test(solution: Solution, name: &str) {
+    let example = Example::read(solution.id, name).unwrap();
+
+    if example.expected.is_empty() {
+        return;
+    }
+
+    for check in check(&example.expected, &example.run(solution)) {
+        assert!(
+            check.is_success(),
+            "{name}, part {}: expected {:?}, got {:?}",
+            check.part,
+            check.expected,
+            check.actual
+        );
+    }
+}
+
+/// Runs all examples of a solution and prints their answers next to the expected ones.
+/// Returns whether all expectations were met.
+pub fn run_all(solution: Solution) -> bool {
+    let examples = match Example::read_all(solution.id) {
+        Ok(examples) => examples,
+        Err(e) => {
+            eprintln!("Failed to read examples: {e}");
+            return false;
+        }
+    };
+
+    let mut checks = vec![];
+
+    for example in &examples {
+        println!("{ANSI_BOLD}{}{ANSI_RESET}", example.name);
+        let records = example.run(solution);
+        checks.push((&example.name, check(&example.expected, &records)));
+        println!();
+    }
+
+    println!(
+        "{ANSI_BOLD}{:<24} {:<8} {:<20} {:<20} Status{ANSI_RESET}",
+        "Example", "Part", "Expected", "Actual"
+    );
+
+    let mut success = true;
+
+    for (name, part_checks) in checks {
+        for check in part_checks {
+            let status = match (&check.expected, check.is_success()) {
+                (None, _) => "- no answer".to_string(),
+                (Some(_), true) => format!("{ANSI_GREEN}✔ correct{ANSI_RESET}"),
+                (Some(_), false) => format!("{ANSI_RED}✖ wrong{ANSI_RESET}"),
+            };
+
+            success &= check.is_success();
+
+            println!(
+                "{:<24} {:<8} {:<20} {:<20} {status}",
+                name,
+                format!("Part {}", check.part),
+                shorten(check.expected.as_deref()),
+                shorten(check.actual.as_deref()),
+            );
+        }
+    }
+
+    success
+}
+
+fn parse_expectations(s: &str) -> Result<(Answers, HashMap<String, String>), String> {
+    let expected = Answers::from_str(s)?;
+
+    let json = JsonValue::from_str(s).or(Err("expectations are not valid JSON."))?;
+    let params = match json
+        .get::<HashMap<String, JsonValue>>()
+        .and_then(|map| map.get("params"))
+    {
+        None => HashMap::new(),
+        Some(JsonValue::Object(params)) => params
+            .iter()
+            .map(|(key, value)| match value {
+                JsonValue::String(x) => Ok((key.clone(), x.clone())),
+                JsonValue::Number(x) => Ok((key.clone(), x.to_string())),
+                JsonValue::Boolean(x) => Ok((key.clone(), x.to_string())),
+                _ => Err(format!(
+                    "Expected params.{key} to be a string, number or boolean."
+                )),
+            })
+            .collect::<Result<_, _>>()?,
+        Some(_) => return Err("Expected params to be an object.".into()),
+    };
+
+    Ok((expected, params))
+}
+
+/// Generates one test per file in `data/<year>/day_XX/examples`, see [`test`].
+/// The list of files is collected by the build script, keyed by the path of the solution file.
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        #[cfg(test)]
+        mod example_tests {
+            include!(concat!(env!("OUT_DIR"), "/examples/", file!()));
+        }
+    };
+}
+
+/// Generates the test of a single example file, used by `example_tests!`.
+#[macro_export]
+macro_rules! example_test {
+    ($name:ident, $file:literal) => {
+        #[test]
+        fn $name() {
+            $crate::template::examples::test(super::SOLUTION, $file);
+        }
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PartCheck, check, param, parse_expectations};
+    use crate::{
+        day,
+        template::{
+            answers::Answers,
+            heap::HeapStats,
+            records::{PartRecord, PartStatus},
+        },
+    };
+    use std::time::Duration;
+
+    fn record(part: u8, answer: Option<&str>) -> PartRecord {
+        PartRecord {
+            day: day!(1),
+            part,
+            status: if answer.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            },
+            answer: answer.map(Into::into),
+            samples: 1,
+            outliers: 0,
+            median: Duration::ZERO,
+            std_dev: Duration::ZERO,
+            min: Duration::ZERO,
+            max: Duration::ZERO,
+            p95: Duration::ZERO,
+            p99: Duration::ZERO,
+            heap: HeapStats::default(),
+            variant: None,
+        }
+    }
+
+    #[test]
+    fn parses_expectations() {
+        let (expected, params) =
+            parse_expectations(r#"{ "part_1": 40, "params": { "connections": 10 } }"#).unwrap();
+        assert_eq!(expected.get(1), Some(&"40".to_string()));
+        assert_eq!(expected.get(2), None);
+        assert_eq!(params.get("connections"), Some(&"10".to_string()));
+    }
+
+    #[test]
+    fn checks_answers() {
+        let expected = Answers {
+            part_1: Some("40".into()),
+            part_2: Some("7".into()),
+        };
+        let checks = check(&expected, &[record(1, Some("40")), record(2, None)]);
+
+        assert_eq!(checks[0].is_success(), true);
+        assert_eq!(
+            checks[1],
+            PartCheck {
+                part: 2,
+                expected: Some("7".into()),
+                actual: None
+            }
+        );
+        assert_eq!(checks[1].is_success(), false);
+    }
+
+    #[test]
+    fn reads_no_params_outside_of_examples() {
+        assert_eq!(param::<usize>("connections"), None);
+    }
+}
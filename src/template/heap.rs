@@ -0,0 +1,196 @@
+/// Heap usage of solution parts.
+/// By default, a counting global allocator tracks the allocations of every call passed to [`measure`].
+/// Outside of [`measure`], e.g. while benching, it only checks a flag and leaves the counters alone.
+/// With the `dhat-heap` feature, the numbers come from DHAT instead and every call writes its own report.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+/// Heap usage of a single call.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HeapStats {
+    /// Number of allocations, reallocations included.
+    pub allocations: u64,
+    /// Bytes allocated over all allocations.
+    pub total_bytes: u64,
+    /// Highest number of bytes that were allocated at the same time.
+    pub peak_bytes: u64,
+}
+
+/// Number of calls to [`measure`] that are running, allocations are only counted during these.
+static MEASURING: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static TOTAL_BYTES: AtomicU64 = AtomicU64::new(0);
+static CURRENT_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Global allocator that wraps the system allocator and counts allocations.
+pub struct CountingAlloc;
+
+fn record_alloc(size: usize) {
+    if MEASURING.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+
+    let size = size as u64;
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    TOTAL_BYTES.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+}
+
+fn record_dealloc(size: usize) {
+    if MEASURING.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+
+    // NOTE: blocks allocated before counting started may be freed, these were never added.
+    let _ = CURRENT_BYTES.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+        Some(current.saturating_sub(size as u64))
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        // NOTE: like DHAT, a reallocation counts as a new allocation of `new_size` bytes.
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Runs `func` once and returns its result along with the heap usage of the call.
+/// `name` identifies the call, it is only used for the DHAT report file.
+#[cfg(not(feature = "dhat-heap"))]
+pub fn measure<T>(_name: &str, func: impl FnOnce() -> T) -> (T, HeapStats) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let total_bytes = TOTAL_BYTES.load(Ordering::Relaxed);
+    let current_bytes = CURRENT_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(current_bytes, Ordering::Relaxed);
+
+    MEASURING.fetch_add(1, Ordering::Relaxed);
+    let result = func();
+    MEASURING.fetch_sub(1, Ordering::Relaxed);
+
+    let stats = HeapStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        total_bytes: TOTAL_BYTES.load(Ordering::Relaxed) - total_bytes,
+        peak_bytes: PEAK_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(current_bytes),
+    };
+
+    (result, stats)
+}
+
+/// Runs `func` once under a DHAT profiler, which writes its report to `dhat-heap-<name>.json`.
+#[cfg(feature = "dhat-heap")]
+pub fn measure<T>(name: &str, func: impl FnOnce() -> T) -> (T, HeapStats) {
+    let profiler = dhat::Profiler::builder()
+        .file_name(format!("dhat-heap-{name}.json"))
+        .build();
+
+    let result = func();
+
+    let stats = dhat::HeapStats::get();
+    drop(profiler);
+
+    let stats = HeapStats {
+        allocations: stats.total_blocks,
+        total_bytes: stats.total_bytes,
+        peak_bytes: stats.max_bytes as u64,
+    };
+
+    (result, stats)
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `512 B` or `1.5 KiB`.
+#[must_use]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+impl HeapStats {
+    /// Short summary, e.g. `12 allocs, 3.4 KiB total, 1.0 KiB peak`.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        format!(
+            "{} allocs, {} total, {} peak",
+            self.allocations,
+            format_bytes(self.total_bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{HeapStats, format_bytes, measure};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn summarizes_heap_stats() {
+        let stats = HeapStats {
+            allocations: 12,
+            total_bytes: 3481,
+            peak_bytes: 1024,
+        };
+        assert_eq!(stats.summary(), "12 allocs, 3.4 KiB total, 1.0 KiB peak");
+    }
+
+    #[test]
+    #[cfg(not(feature = "dhat-heap"))]
+    fn measures_allocations() {
+        // NOTE: counters are global, other test threads may add to them but never subtract.
+        let (values, stats) = measure("vec", || vec![0_u64; 1000]);
+        assert_eq!(values.len(), 1000);
+        assert_eq!(stats.allocations >= 1, true);
+        assert_eq!(stats.total_bytes >= 8000, true);
+        assert_eq!(stats.peak_bytes <= stats.total_bytes, true);
+    }
+}
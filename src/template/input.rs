@@ -0,0 +1,87 @@
+/// Source of the input a solution runs on, selected with `--input <path>`.
+/// `--input -` reads the input from stdin, the day's `input.txt` is used otherwise.
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+};
+
+use crate::input_path;
+use crate::template::PuzzleId;
+
+pub const INPUT_FLAG: &str = "--input";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// `data/<year>/day_XX/input.txt`.
+    Default,
+    File(String),
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads the source from the `--input` argument.
+    #[must_use]
+    pub fn from_args(args: &[String]) -> Self {
+        let value = args
+            .iter()
+            .position(|x| x == INPUT_FLAG)
+            .and_then(|i| args.get(i + 1));
+
+        match value.map(String::as_str) {
+            None => InputSource::Default,
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.to_string()),
+        }
+    }
+
+    /// Whether the solution runs on the day's own input.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == InputSource::Default
+    }
+
+    pub fn read(&self, id: PuzzleId) -> Result<String, io::Error> {
+        match self {
+            InputSource::Default => fs::read_to_string(format!(input_path!(), id.year, id.day)),
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Default => write!(f, "input.txt"),
+            InputSource::File(path) => write!(f, "{path}"),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputSource;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn reads_source_from_args() {
+        assert_eq!(InputSource::from_args(&args(&["09"])), InputSource::Default);
+        assert_eq!(
+            InputSource::from_args(&args(&["09", "--input", "-"])),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::from_args(&args(&["09", "--input", "alt/input.txt", "--submit", "1"])),
+            InputSource::File("alt/input.txt".into())
+        );
+    }
+}
@@ -0,0 +1,440 @@
+/// Private leaderboards, in the JSON format of `/{year}/leaderboard/private/view/{id}.json`.
+/// Stars and local scores are computed from the completion timestamps instead of being taken
+/// from the file, and the standings can be rendered into the readme.
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+use crate::template::readme_benchmarks::{Error, replace_table};
+
+static MARKER: &str = "<!--- leaderboard table --->";
+
+/// When a member got the stars of a day, as unix timestamps in seconds.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Completion {
+    pub part_1: Option<u64>,
+    pub part_2: Option<u64>,
+}
+
+impl Completion {
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<u64> {
+        match part {
+            1 => self.part_1,
+            2 => self.part_2,
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn stars(&self) -> u32 {
+        u32::from(self.part_1.is_some()) + u32::from(self.part_2.is_some())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// Members without a name show up as anonymous users.
+    pub name: Option<String>,
+    pub days: BTreeMap<Day, Completion>,
+    pub local_score: u64,
+}
+
+impl Member {
+    #[must_use]
+    pub fn stars(&self) -> u32 {
+        self.days.values().map(Completion::stars).sum()
+    }
+
+    /// Timestamp of the last star, members who got there first rank higher on a tie.
+    #[must_use]
+    pub fn last_star(&self) -> Option<u64> {
+        self.days
+            .values()
+            .flat_map(|completion| [completion.part_1, completion.part_2])
+            .flatten()
+            .max()
+    }
+
+    #[must_use]
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+}
+
+/// The members of a private leaderboard, ranked by local score.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    /// Reads a leaderboard that was saved from the website.
+    pub fn read(path: &str) -> Result<Self, String> {
+        let content = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+        Leaderboard::from_str(&content).map_err(|e| format!("{path}: {e}"))
+    }
+
+    /// The last day that any member got a star on.
+    #[must_use]
+    pub fn last_day(&self) -> Option<Day> {
+        self.members
+            .iter()
+            .filter_map(|member| member.days.keys().next_back())
+            .max()
+            .copied()
+    }
+
+    /// Scores every part like the website does: the first of `n` members to get a star
+    /// gets `n` points, the second one `n - 1` and so on.
+    fn score(&mut self) {
+        let member_count = self.members.len() as u64;
+
+        // NOTE: stars from the same second are ordered by member id, the file has no finer order.
+        let mut finishers: HashMap<(Day, u8), Vec<(u64, u64)>> = HashMap::new();
+
+        for member in &self.members {
+            for (day, completion) in &member.days {
+                for part in [1, 2] {
+                    if let Some(timestamp) = completion.get(part) {
+                        finishers
+                            .entry((*day, part))
+                            .or_default()
+                            .push((timestamp, member.id));
+                    }
+                }
+            }
+        }
+
+        let mut scores: HashMap<u64, u64> = HashMap::new();
+
+        for mut finishers in finishers.into_values() {
+            finishers.sort_unstable();
+
+            for (rank, (_, id)) in finishers.into_iter().enumerate() {
+                *scores.entry(id).or_default() += member_count - rank as u64;
+            }
+        }
+
+        for member in &mut self.members {
+            member.local_score = scores.get(&member.id).copied().unwrap_or_default();
+        }
+    }
+
+    fn rank(&mut self) {
+        self.members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars().cmp(&a.stars()))
+                .then(a.last_star().cmp(&b.last_star()))
+                .then(a.id.cmp(&b.id))
+        });
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn format_stars(completion: Option<&Completion>) -> &'static str {
+    match completion.map(Completion::stars) {
+        Some(2) => "⭐",
+        Some(1) => "☆",
+        _ => " ",
+    }
+}
+
+fn construct_table(prefix: &str, leaderboard: &Leaderboard) -> String {
+    let days: Vec<Day> = match leaderboard.last_day() {
+        Some(last_day) => (1..=last_day.into_inner()).filter_map(Day::new).collect(),
+        None => vec![],
+    };
+
+    let day_headers: String = days
+        .iter()
+        .map(|d| format!(" {} |", d.into_inner()))
+        .collect();
+    let day_alignment = " :---: |".repeat(days.len());
+
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        format!("{prefix} {} Leaderboard", leaderboard.event),
+        String::new(),
+        format!("| # | Member | Score | Stars |{day_headers}"),
+        format!("| :---: | :--- | :---: | :---: |{day_alignment}"),
+    ];
+
+    for (rank, member) in leaderboard.members.iter().enumerate() {
+        let day_cells: String = days
+            .iter()
+            .map(|day| format!(" {} |", format_stars(member.days.get(day))))
+            .collect();
+
+        lines.push(format!(
+            "| {} | {} | {} | {} |{day_cells}",
+            rank + 1,
+            member.display_name(),
+            member.local_score,
+            member.stars()
+        ));
+    }
+
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, leaderboard: &Leaderboard) -> Result<(), Error> {
+    let table = construct_table("##", leaderboard);
+    replace_table(s, MARKER, &table)
+}
+
+pub fn update(leaderboard: &Leaderboard) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, leaderboard)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn parse_number(value: &JsonValue, key: &str) -> Result<u64, String> {
+    match value {
+        JsonValue::Number(x) if *x >= 0.0 && x.fract() == 0.0 => Ok(*x as u64),
+        // NOTE: ids are numbers in some years and strings in others.
+        JsonValue::String(x) => x
+            .parse()
+            .map_err(|_| format!("Expected {key} to be a number.")),
+        _ => Err(format!("Expected {key} to be a number.")),
+    }
+}
+
+fn parse_completion(value: &JsonValue, key: &str) -> Result<Completion, String> {
+    let parts = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or(format!("Expected {key} to be an object."))?;
+
+    let timestamp = |part: &str| -> Result<Option<u64>, String> {
+        let Some(part) = parts.get(part) else {
+            return Ok(None);
+        };
+
+        let star = part
+            .get::<HashMap<String, JsonValue>>()
+            .and_then(|star| star.get("get_star_ts"))
+            .ok_or(format!("Expected {key}.get_star_ts to be set."))?;
+
+        parse_number(star, &format!("{key}.get_star_ts")).map(Some)
+    };
+
+    Ok(Completion {
+        part_1: timestamp("1")?,
+        part_2: timestamp("2")?,
+    })
+}
+
+/// Parses the member stored under `id` in `members`.
+fn parse_member(id: &str, value: &JsonValue) -> Result<Member, String> {
+    let key = format!("members.{id}");
+
+    let member = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or(format!("Expected {key} to be an object."))?;
+
+    let id = parse_number(
+        member.get("id").unwrap_or(&JsonValue::String(id.into())),
+        &format!("{key}.id"),
+    )?;
+
+    let name = match member.get("name") {
+        None | Some(JsonValue::Null) => None,
+        Some(JsonValue::String(name)) => Some(name.clone()),
+        Some(_) => return Err(format!("Expected {key}.name to be null or string.")),
+    };
+
+    let mut days = BTreeMap::new();
+
+    if let Some(levels) = member.get("completion_day_level") {
+        let levels = levels.get::<HashMap<String, JsonValue>>().ok_or(format!(
+            "Expected {key}.completion_day_level to be an object."
+        ))?;
+
+        for (day, completion) in levels {
+            let day = Day::from_str(day).map_err(|e| format!("{key}: {e}"))?;
+            let completion = parse_completion(completion, &format!("{key}.{day}"))?;
+            days.insert(day, completion);
+        }
+    }
+
+    Ok(Member {
+        id,
+        name,
+        days,
+        local_score: 0,
+    })
+}
+
+impl FromStr for Leaderboard {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("leaderboard is not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected leaderboard to be a JSON object.")?;
+
+        let event = match json.get("event") {
+            Some(JsonValue::String(event)) => event.clone(),
+            _ => return Err("Expected event to be a string.".into()),
+        };
+
+        let members = json
+            .get("members")
+            .and_then(|members| members.get::<HashMap<String, JsonValue>>())
+            .ok_or("Expected members to be an object.")?
+            .iter()
+            .map(|(id, member)| parse_member(id, member))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut leaderboard = Leaderboard { event, members };
+        leaderboard.score();
+        leaderboard.rank();
+
+        Ok(leaderboard)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Completion, Leaderboard, MARKER, Member, update_content};
+    use crate::day;
+    use std::{collections::BTreeMap, str::FromStr};
+
+    const PRIVATE_LEADERBOARD: &str = include_str!("fixtures/leaderboard/private.json");
+
+    fn get_leaderboard() -> Leaderboard {
+        Leaderboard::from_str(PRIVATE_LEADERBOARD).unwrap()
+    }
+
+    #[test]
+    fn computes_stars_and_local_scores() {
+        let leaderboard = get_leaderboard();
+
+        let standings: Vec<_> = leaderboard
+            .members
+            .iter()
+            .map(|m| (m.display_name(), m.stars(), m.local_score))
+            .collect();
+
+        assert_eq!(
+            standings,
+            vec![
+                ("Bob".to_string(), 4, 15),
+                ("Alice".to_string(), 3, 10),
+                ("(anonymous user #3)".to_string(), 1, 2),
+                ("Carol".to_string(), 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_completion_timestamps() {
+        let leaderboard = get_leaderboard();
+        let alice = &leaderboard.members[1];
+
+        assert_eq!(
+            alice.days.get(&day!(1)),
+            Some(&Completion {
+                part_1: Some(1_764_565_300),
+                part_2: Some(1_764_565_400),
+            })
+        );
+        assert_eq!(alice.days[&day!(2)].part_2, None);
+        assert_eq!(alice.last_star(), Some(1_764_652_600));
+        assert_eq!(leaderboard.last_day(), Some(day!(2)));
+    }
+
+    #[test]
+    fn ranks_ties_by_last_star() {
+        let member = |id: u64, day_1: u64, day_2: u64| Member {
+            id,
+            name: None,
+            days: BTreeMap::from([
+                (
+                    day!(1),
+                    Completion {
+                        part_1: Some(day_1),
+                        part_2: None,
+                    },
+                ),
+                (
+                    day!(2),
+                    Completion {
+                        part_1: Some(day_2),
+                        part_2: None,
+                    },
+                ),
+            ]),
+            local_score: 0,
+        };
+
+        let mut leaderboard = Leaderboard {
+            event: "2025".into(),
+            members: vec![member(1, 100, 2000), member(2, 200, 1900)],
+        };
+        leaderboard.score();
+        leaderboard.rank();
+
+        // NOTE: both scored 3 points, member 2 got the last star first.
+        let ids: Vec<_> = leaderboard
+            .members
+            .iter()
+            .map(|m| (m.id, m.local_score))
+            .collect();
+        assert_eq!(ids, vec![(2, 3), (1, 3)]);
+    }
+
+    #[test]
+    fn rejects_invalid_leaderboards() {
+        assert_eq!(Leaderboard::from_str("[]").is_err(), true);
+        assert_eq!(
+            Leaderboard::from_str(r#"{ "event": "2025", "members": { "1": { "name": 7 } } }"#)
+                .unwrap_err(),
+            "Expected members.1.name to be null or string."
+        );
+    }
+
+    #[test]
+    fn format_leaderboard() {
+        let mut s = format!("foo\n{}\n{}\nbar", MARKER, MARKER);
+        update_content(&mut s, &get_leaderboard()).unwrap();
+
+        let expected = [
+            "foo",
+            "<!--- leaderboard table --->",
+            "## 2025 Leaderboard",
+            "",
+            "| # | Member | Score | Stars | 1 | 2 |",
+            "| :---: | :--- | :---: | :---: | :---: | :---: |",
+            "| 1 | Bob | 15 | 4 | ⭐ | ⭐ |",
+            "| 2 | Alice | 10 | 3 | ⭐ | ☆ |",
+            "| 3 | (anonymous user #3) | 2 | 1 | ☆ |   |",
+            "| 4 | Carol | 0 | 0 |   |   |",
+            "<!--- leaderboard table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
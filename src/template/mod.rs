@@ -1,27 +1,47 @@
 use std::{env, fs};
 
+use crate::day_path;
+
+pub mod answers;
 pub mod aoc_cli;
+#[cfg(feature = "native_client")]
+pub mod aoc_client;
+pub mod backend;
 pub mod commands;
+pub mod examples;
+pub mod heap;
+pub mod input;
+pub mod leaderboard;
+pub mod puzzle;
+pub mod records;
+pub mod registry;
+pub mod regression;
 pub mod runner;
+pub mod submissions;
+pub mod variants;
 
 pub use day::*;
+pub use run_multi::RunOptions;
+pub use year::*;
 
 mod day;
 mod readme_benchmarks;
 mod run_multi;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file_input(folder: &str, day: Day, name: &str) -> String {
+pub fn read_file_input(folder: &str, id: PuzzleId, name: &str) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
-        .join(format!("day_{day}"))
+        .join(format!(day_path!(), id.year, id.day))
         .join(folder)
         .join(name);
 
@@ -29,9 +49,13 @@ pub fn read_file_input(folder: &str, day: Day, name: &str) -> String {
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR`, `DAY` and `PUZZLE` and sets up the input and runner for each part.
+/// The year is read from the name of the solution file, e.g. `src/bin/2025_09.rs`.
+///
+/// The optional `parse = <fn>` parameter runs the given function once on the input and hands
+/// a reference to its output to both parts. Parsing is then timed separately from the parts.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The optional, last parameter (1 or 2) allows you to only run a single part of the solution.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
@@ -43,19 +67,95 @@ macro_rules! solution {
     ($day:expr, 2) => {
         $crate::solution!(@impl $day, [part_two, 2]);
     };
+    ($day:expr, parse = $parse:expr) => {
+        $crate::solution!(@impl_parsed $day, $parse, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, parse = $parse:expr, 1) => {
+        $crate::solution!(@impl_parsed $day, $parse, [part_one, 1]);
+    };
+    ($day:expr, parse = $parse:expr, 2) => {
+        $crate::solution!(@impl_parsed $day, $parse, [part_two, 2]);
+    };
 
     (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@header $day, |input| {
+            use $crate::template::runner::*;
+            vec![$( run_part($func, input, PUZZLE, $part) ),*]
+        });
+    };
+
+    (@impl_parsed $day:expr, $parse:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@header $day, |input| {
+            use $crate::template::runner::*;
+            let (parsed, parse_record) = run_parse($parse, input, PUZZLE);
+            vec![parse_record, $( run_part($func, &parsed, PUZZLE, $part) ),*]
+        });
+    };
+
+    (@header $day:expr, |$input:ident| $body:block) => {
+        /// The year of the current day.
+        const YEAR: $crate::template::Year = const {
+            $crate::template::Year::from_path(file!())
+                .expect("solution files must be named `<year>_<day>.rs`, e.g. `2025_09.rs`")
+        };
+
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+        /// The puzzle of the current day.
+        const PUZZLE: $crate::template::PuzzleId = $crate::template::PuzzleId::new(YEAR, DAY);
+
+        #[allow(unused_imports)]
+        use self::no_variants::*;
+
+        /// Fallback for days without `variants!`, the `VARIANTS` it generates shadow this one.
+        mod no_variants {
+            pub const VARIANTS: $crate::template::variants::Variants =
+                $crate::template::variants::Variants::none(super::PUZZLE);
+        }
+
+        /// Entry point of this day, picked up by the in-process registry.
+        pub const SOLUTION: $crate::template::registry::Solution = {
+            fn solve($input: &str) -> Vec<$crate::template::records::PartRecord> $body
+
+            $crate::template::registry::Solution { id: PUZZLE, solve }
+        };
 
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file_input("", DAY, "input.txt");
-            $( run_part($func, &input, DAY, $part); )*
+            if std::env::args().any(|x| x == "--examples") {
+                if !$crate::template::examples::run_all(SOLUTION) {
+                    std::process::exit(1);
+                }
+                return;
+            }
+
+            let args: Vec<String> = std::env::args().collect();
+            let source = $crate::template::input::InputSource::from_args(&args);
+
+            let input = match source.read(PUZZLE) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("Failed to read input from {source}: {e}");
+                    std::process::exit(1);
+                }
+            };
+
+            if !source.is_default() {
+                println!(
+                    "{}Input: {source}{}",
+                    $crate::template::ANSI_ITALIC,
+                    $crate::template::ANSI_RESET
+                );
+            }
+
+            if args.iter().any(|x| x == "--variants") {
+                (VARIANTS.bench)(&input);
+                return;
+            }
+
+            (SOLUTION.solve)(&input);
         }
+
+        $crate::example_tests!();
     };
 }
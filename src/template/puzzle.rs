@@ -0,0 +1,465 @@
+/// Conversion of Advent of Code puzzle pages. Only the `<article>` elements of a page hold the
+/// puzzle, everything around them (navigation, answer forms, sponsors) is ignored.
+use crate::template::answers::Answers;
+use crate::template::examples::Example;
+
+/// Elements that never have children.
+const VOID_ELEMENTS: [&str; 8] = ["br", "hr", "img", "input", "link", "meta", "source", "wbr"];
+
+/// Elements whose content is not markup.
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Node {
+    Element {
+        name: String,
+        attrs: Vec<(String, String)>,
+        children: Vec<Node>,
+    },
+    Text(String),
+}
+
+impl Node {
+    fn name(&self) -> Option<&str> {
+        match self {
+            Node::Element { name, .. } => Some(name),
+            Node::Text(_) => None,
+        }
+    }
+
+    fn children(&self) -> &[Node] {
+        match self {
+            Node::Element { children, .. } => children,
+            Node::Text(_) => &[],
+        }
+    }
+
+    fn attr(&self, key: &str) -> Option<&str> {
+        match self {
+            Node::Element { attrs, .. } => attrs
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str()),
+            Node::Text(_) => None,
+        }
+    }
+
+    /// The text of the node and all of its descendants.
+    fn text(&self) -> String {
+        match self {
+            Node::Element { children, .. } => children.iter().map(Node::text).collect(),
+            Node::Text(text) => text.clone(),
+        }
+    }
+
+    /// All descendants named `name`, in document order.
+    fn find_all<'a>(&'a self, name: &str, found: &mut Vec<&'a Node>) {
+        for child in self.children() {
+            if child.name() == Some(name) {
+                found.push(child);
+            }
+            child.find_all(name, found);
+        }
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        let mut found = vec![];
+        self.find_all(name, &mut found);
+        !found.is_empty()
+    }
+}
+
+/// An element that is still open while parsing: its name, attributes and children so far.
+type Open = (String, Vec<(String, String)>, Vec<Node>);
+
+/// Parses forgiving HTML into a tree. Closing tags without an open element are ignored,
+/// elements that are still open at the end are closed.
+fn parse(html: &str) -> Node {
+    let mut stack: Vec<Open> = vec![(String::new(), vec![], vec![])];
+
+    let close = |stack: &mut Vec<Open>| {
+        let (name, attrs, children) = stack.pop().unwrap();
+        let element = Node::Element {
+            name,
+            attrs,
+            children,
+        };
+        stack.last_mut().unwrap().2.push(element);
+    };
+
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        if rest.starts_with('<')
+            && let Some(end) = rest.find('>')
+        {
+            let tag = &rest[1..end];
+            rest = &rest[end + 1..];
+
+            if let Some(name) = tag.strip_prefix('/') {
+                let name = name.trim().to_lowercase();
+                if let Some(position) = stack.iter().rposition(|(n, _, _)| *n == name)
+                    && position > 0
+                {
+                    while stack.len() > position {
+                        close(&mut stack);
+                    }
+                }
+            } else if !tag.starts_with('!') && !tag.starts_with('?') {
+                let is_self_closing = tag.ends_with('/');
+                let (name, attrs) = parse_tag(tag.trim_end_matches('/'));
+
+                if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+                    let closing_tag = format!("</{name}>");
+                    rest = rest.find(&closing_tag).map_or("", |end| &rest[end..]);
+                }
+
+                stack.push((name, attrs, vec![]));
+
+                let name = &stack.last().unwrap().0;
+                if is_self_closing || VOID_ELEMENTS.contains(&name.as_str()) {
+                    close(&mut stack);
+                }
+            }
+
+            continue;
+        }
+
+        let end = rest[1..].find('<').map_or(rest.len(), |end| end + 1);
+        let text = decode_entities(&rest[..end]);
+        stack.last_mut().unwrap().2.push(Node::Text(text));
+        rest = &rest[end..];
+    }
+
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+
+    let (_, _, children) = stack.pop().unwrap();
+    Node::Element {
+        name: String::new(),
+        attrs: vec![],
+        children,
+    }
+}
+
+/// Splits the inside of a start tag into its lowercase name and its attributes.
+fn parse_tag(tag: &str) -> (String, Vec<(String, String)>) {
+    let tag = tag.trim();
+    let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+    let name = tag[..name_end].to_lowercase();
+
+    let mut attrs = vec![];
+    let mut rest = tag[name_end..].trim_start();
+
+    while !rest.is_empty() {
+        let key_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let key = rest[..key_end].to_lowercase();
+        rest = rest[key_end..].trim_start();
+
+        let value = if let Some(value) = rest.strip_prefix('=') {
+            let value = value.trim_start();
+            let (value, remainder) = match value.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let end = value[1..].find(quote).map_or(value.len(), |end| end + 1);
+                    (&value[1..end], value.get(end + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let end = value.find(char::is_whitespace).unwrap_or(value.len());
+                    (&value[..end], &value[end..])
+                }
+            };
+            rest = remainder.trim_start();
+            decode_entities(value)
+        } else {
+            String::new()
+        };
+
+        attrs.push((key, value));
+    }
+
+    (name, attrs)
+}
+
+/// Replaces named and numeric character references.
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .get(1..rest.find(';').unwrap_or(0))
+            .filter(|entity| entity.len() <= 8);
+
+        let c = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = match entity.strip_prefix("#x").or(entity.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => entity.strip_prefix('#')?.parse().ok(),
+                };
+                code.and_then(char::from_u32)
+            }
+        });
+
+        match (c, entity) {
+            (Some(c), Some(entity)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn articles(root: &Node) -> Vec<&Node> {
+    let mut articles = vec![];
+    root.find_all("article", &mut articles);
+    articles
+}
+
+/// Converts the puzzle description of a page to Markdown.
+#[must_use]
+pub fn to_markdown(html: &str) -> String {
+    let root = parse(html);
+
+    let markdown: Vec<String> = articles(&root)
+        .into_iter()
+        .map(|article| render_blocks(article.children()).trim().to_string())
+        .filter(|article| !article.is_empty())
+        .collect();
+
+    markdown.join("\n\n")
+}
+
+/// Whether a stored description predates the first star: part 1 is solved, but the
+/// description does not show part 2 yet.
+#[must_use]
+pub fn is_stale(description: &str, answers: &Answers) -> bool {
+    answers.part_1.is_some() && !description.contains("--- Part Two ---")
+}
+
+/// Renders block elements, whitespace between them is dropped.
+fn render_blocks(nodes: &[Node]) -> String {
+    nodes
+        .iter()
+        .filter(|node| !matches!(node, Node::Text(text) if text.trim().is_empty()))
+        .map(|node| match node.name() {
+            Some("h2") => format!("## {}\n\n", render_inline(node.children()).trim()),
+            Some("p") => format!("{}\n\n", render_inline(node.children()).trim()),
+            Some("pre") => format!("```\n{}\n```\n\n", node.text().trim_end_matches('\n')),
+            Some("ul") => format!("{}\n", render_blocks(node.children())),
+            Some("li") => format!("- {}\n", render_inline(node.children()).trim()),
+            _ => render_inline(std::slice::from_ref(node)),
+        })
+        .collect()
+}
+
+fn render_inline(nodes: &[Node]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            Node::Text(text) => text.clone(),
+            Node::Element { name, children, .. } => match name.as_str() {
+                // NOTE: emphasis can not go inside code, so it wraps it either way.
+                "code" if node.contains("em") => format!("*`{}`*", node.text()),
+                "code" => format!("`{}`", node.text()),
+                "em" => format!("*{}*", render_inline(children)),
+                "a" => match node.attr("href") {
+                    Some(href) => format!("[{}]({href})", render_inline(children)),
+                    None => render_inline(children),
+                },
+                "br" => "\n".into(),
+                _ => render_inline(children),
+            },
+        })
+        .collect()
+}
+
+/// Finds the examples of a puzzle page along with their expected answers.
+///
+/// The first `<pre><code>` block of a part is taken as its example input, and the last
+/// emphasized code (`<code><em>..</em></code>`) of the part as the answer for that example.
+/// A part without a block of its own uses the example of the previous part.
+#[must_use]
+pub fn extract_examples(html: &str) -> Vec<Example> {
+    let root = parse(html);
+    let mut examples: Vec<Example> = vec![];
+
+    for (part, article) in (1..=2).zip(articles(&root)) {
+        let mut blocks = vec![];
+        article.find_all("pre", &mut blocks);
+
+        let input = blocks
+            .first()
+            .map(|pre| pre.text().trim_end_matches('\n').to_string());
+
+        let index = match input {
+            Some(input) => examples
+                .iter()
+                .position(|example| example.input == input)
+                .unwrap_or_else(|| {
+                    examples.push(Example {
+                        name: format!("example_{}.txt", examples.len() + 1),
+                        input,
+                        ..Example::default()
+                    });
+                    examples.len() - 1
+                }),
+            None if examples.is_empty() => continue,
+            None => examples.len() - 1,
+        };
+
+        if let Some(answer) = emphasized_answers(article).last() {
+            examples[index].expected.set(part, answer.clone());
+        }
+    }
+
+    examples
+}
+
+/// The text of every emphasized piece of code, in document order.
+fn emphasized_answers(article: &Node) -> Vec<String> {
+    let mut elements = vec![];
+    collect_emphasized_code(article, &mut elements);
+
+    elements
+        .into_iter()
+        .map(|node| node.text().trim().to_string())
+        .filter(|answer| !answer.is_empty())
+        .collect()
+}
+
+fn collect_emphasized_code<'a>(node: &'a Node, found: &mut Vec<&'a Node>) {
+    for child in node.children() {
+        let is_emphasized_code = match child.name() {
+            Some("code") => child.contains("em"),
+            Some("em") => child.children().iter().any(|c| c.name() == Some("code")),
+            _ => false,
+        };
+
+        if is_emphasized_code {
+            found.push(child);
+        } else {
+            collect_emphasized_code(child, found);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decode_entities, extract_examples, is_stale, parse, to_markdown};
+    use crate::template::answers::Answers;
+
+    const PART_1_PAGE: &str = include_str!("fixtures/puzzle/day_01_part_1.html");
+    const PART_2_PAGE: &str = include_str!("fixtures/puzzle/day_01_part_2.html");
+    const PART_2_MARKDOWN: &str = include_str!("fixtures/puzzle/day_01_part_2.md");
+
+    #[test]
+    fn converts_pages_to_markdown() {
+        assert_eq!(to_markdown(PART_2_PAGE), PART_2_MARKDOWN.trim_end());
+    }
+
+    #[test]
+    fn converts_only_unlocked_parts() {
+        let markdown = to_markdown(PART_1_PAGE);
+        assert_eq!(markdown.starts_with("## --- Day 1: Sock Drawer ---"), true);
+        assert_eq!(markdown.contains("Part Two"), false);
+        assert_eq!(markdown.contains("get your puzzle input"), false);
+    }
+
+    #[test]
+    fn detects_stale_descriptions() {
+        let solved = Answers {
+            part_1: Some("512".into()),
+            part_2: None,
+        };
+
+        assert_eq!(is_stale(&to_markdown(PART_1_PAGE), &solved), true);
+        assert_eq!(is_stale(PART_2_MARKDOWN, &solved), false);
+        assert_eq!(
+            is_stale(&to_markdown(PART_1_PAGE), &Answers::default()),
+            false
+        );
+    }
+
+    #[test]
+    fn extracts_examples_of_part_1() {
+        let examples = extract_examples(PART_1_PAGE);
+
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].name, "example_1.txt");
+        assert_eq!(examples[0].input, "3\n7\n3\n5\n7");
+        assert_eq!(
+            examples[0].expected,
+            Answers {
+                part_1: Some("2".into()),
+                part_2: None,
+            }
+        );
+    }
+
+    #[test]
+    fn extracts_examples_of_both_parts() {
+        let examples = extract_examples(PART_2_PAGE);
+
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].expected.part_1, Some("2".into()));
+        assert_eq!(examples[0].expected.part_2, None);
+        assert_eq!(examples[1].name, "example_2.txt");
+        assert_eq!(examples[1].input, "3L\n3\n7L\n7L");
+        assert_eq!(examples[1].expected.part_1, None);
+        assert_eq!(examples[1].expected.part_2, Some("1".into()));
+    }
+
+    #[test]
+    fn reuses_examples_for_part_2() {
+        let html = "<article><pre><code>1 2\n</code></pre><p>Sum: <code><em>3</em></code></p></article>\
+            <article><p>Product: <code><em>2</em></code></p></article>";
+        let examples = extract_examples(html);
+
+        assert_eq!(examples.len(), 1);
+        assert_eq!(
+            examples[0].expected,
+            Answers {
+                part_1: Some("3".into()),
+                part_2: Some("2".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp;amp; &#39;c&#x27; & d &unknown;"),
+            "a <b> &amp; 'c' & d &unknown;"
+        );
+    }
+
+    #[test]
+    fn parses_broken_html() {
+        let root = parse("<p>one</span><p>two<br>three");
+        assert_eq!(root.text(), "onetwothree");
+    }
+}
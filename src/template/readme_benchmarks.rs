@@ -2,10 +2,21 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
-use crate::template::timings::Timings;
+use crate::template::heap::HeapStats;
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::timings::{PartStats, Timings};
+use crate::template::{PuzzleId, Year};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+/// Start of the markers of every year, e.g. `<!--- benchmarking table 2025 --->`.
+static MARKER_PREFIX: &str = "<!--- benchmarking table";
+
+/// Marker of readmes from before multi-year support, it is taken over by the first year stored.
+static LEGACY_MARKER: &str = "<!--- benchmarking table --->";
+
+/// Marks the benchmarks of `year`, every year has its own table.
+fn marker(year: Year) -> String {
+    format!("{MARKER_PREFIX} {year} --->")
+}
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -25,18 +36,13 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+        return Err(Error::Parser(format!(
+            "{marker}: too many occurences of marker in README."
+        )));
     }
 
     let pos_start = matches
@@ -52,76 +58,141 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn format_cell(median: Option<String>, stats: Option<&PartStats>, timed_out: bool) -> String {
+    match (median, stats) {
+        (Some(median), Some(stats)) if !stats.std_dev.is_empty() => {
+            format!("{median} ± {}", stats.std_dev)
+        }
+        (Some(median), _) => median,
+        (None, _) if timed_out => "timed out".into(),
+        (None, _) => "-".into(),
+    }
+}
+
+fn format_heap_cell(heap: Option<&HeapStats>) -> String {
+    match heap {
+        Some(heap) => heap.summary(),
+        None => "-".into(),
+    }
+}
+
+fn construct_table(prefix: &str, year: Year, timings: Timings, total_millis: f64) -> String {
+    let header = format!("{prefix} Benchmarks ({year})");
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker(year),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        "| Day | Parse | Part 1 | Part 2 | Heap (Parse) | Heap (Part 1) | Heap (Part 2) |".into(),
+        "| :---: | :---: | :---: | :---: | :---: | :---: | :---:  |".into(),
     ];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(PuzzleId::new(year, timing.day));
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) | `{}` | `{}` | `{}` | `{}` | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            format_cell(timing.parse, timing.parse_stats.as_ref(), false),
+            format_cell(
+                timing.part_1,
+                timing.part_1_stats.as_ref(),
+                timing.timed_out
+            ),
+            format_cell(
+                timing.part_2,
+                timing.part_2_stats.as_ref(),
+                timing.timed_out
+            ),
+            format_heap_cell(timing.parse_heap.as_ref()),
+            format_heap_cell(timing.part_1_heap.as_ref()),
+            format_heap_cell(timing.part_2_heap.as_ref())
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker(year));
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
+/// Replaces the table between the two occurences of `marker`, `table` includes the markers.
+pub(crate) fn replace_table(s: &mut String, marker: &str, table: &str) -> Result<(), Error> {
+    let positions = locate_table(s, marker)?;
+    s.replace_range(positions.pos_start..positions.pos_end, table);
+    Ok(())
+}
+
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let table = construct_table("##", year, timings, total_millis);
+    let marker = marker(year);
+
+    if s.contains(&marker) {
+        return replace_table(s, &marker, &table);
+    }
+
+    if s.contains(LEGACY_MARKER) {
+        return replace_table(s, LEGACY_MARKER, &table);
+    }
+
+    // NOTE: the first table of a year goes below the tables of the other years.
+    let pos_end = s
+        .rfind(MARKER_PREFIX)
+        .and_then(|pos| s[pos..].find("--->").map(|end| pos + end + "--->".len()))
+        .ok_or_else(|| Error::Parser("Could not find a benchmarking table.".into()))?;
+
+    s.insert_str(pos_end, &format!("\n\n{table}"));
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+/// Replaces the benchmarks of `year` in the readme, the tables of other years are kept.
+pub fn update(year: Year, timings: Timings) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{LEGACY_MARKER, marker, update_content};
+    use crate::{
+        day,
+        template::{
+            heap::HeapStats,
+            timings::{PartStats, Timing, Timings},
+        },
+        year,
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -131,53 +202,130 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
-        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = marker(year!(2025));
+        let mut s = format!("{} {} {}", marker, marker, marker);
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = marker(year!(2025));
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
+        let marker = marker(year!(2025));
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.matches(&marker).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
 
+    #[test]
+    fn keeps_benchmarks_of_other_years() {
+        let mut s = format!("foo\n{}{}\nbaz", LEGACY_MARKER, LEGACY_MARKER);
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2016), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2016), get_mock_timings(), 190.0).unwrap();
+
+        assert_eq!(s.contains(LEGACY_MARKER), false);
+        assert_eq!(s.matches(&marker(year!(2025))).count(), 2);
+        assert_eq!(s.matches(&marker(year!(2016))).count(), 2);
+        assert_eq!(
+            s.find("## Benchmarks (2025)") < s.find("## Benchmarks (2016)"),
+            true
+        );
+        assert_eq!(s.ends_with(&format!("{}\nbaz", marker(year!(2016)))), true);
+    }
+
     #[test]
     fn format_benchmarks() {
-        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = marker(year!(2025));
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2025 --->",
+            "## Benchmarks (2025)",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 | Heap (Parse) | Heap (Part 1) | Heap (Part 2) |",
+            "| :---: | :---: | :---: | :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2025_01.rs) | `-` | `10ms` | `20ms` | `-` | `-` | `-` |",
+            "| [Day 2](./src/bin/2025_02.rs) | `-` | `30ms` | `40ms` | `-` | `-` | `-` |",
+            "| [Day 4](./src/bin/2025_04.rs) | `-` | `40ms` | `50ms` | `-` | `-` | `-` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table 2025 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_timed_out_parts() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_2 = None;
+        timings.data[0].timed_out = true;
+
+        let marker = marker(year!(2025));
+        let mut s = format!("{}{}", marker, marker);
+        update_content(&mut s, year!(2025), timings, 190.0).unwrap();
+        assert_eq!(
+            s.contains(
+                "| [Day 1](./src/bin/2025_01.rs) | `-` | `10ms` | `timed out` | `-` | `-` | `-` |"
+            ),
+            true
+        );
+    }
+
+    #[test]
+    fn format_benchmarks_with_spread() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_stats = Some(PartStats {
+            std_dev: "1.5ms".into(),
+            ..Default::default()
+        });
+
+        let marker = marker(year!(2025));
+        let mut s = format!("{}{}", marker, marker);
+        update_content(&mut s, year!(2025), timings, 190.0).unwrap();
+        assert_eq!(
+            s.contains(
+                "| [Day 1](./src/bin/2025_01.rs) | `-` | `10ms ± 1.5ms` | `20ms` | `-` | `-` | `-` |"
+            ),
+            true
+        );
+    }
+
+    #[test]
+    fn format_benchmarks_with_heap() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_heap = Some(HeapStats {
+            allocations: 12,
+            total_bytes: 8192,
+            peak_bytes: 1536,
+        });
+
+        let marker = marker(year!(2025));
+        let mut s = format!("{}{}", marker, marker);
+        update_content(&mut s, year!(2025), timings, 190.0).unwrap();
+        assert_eq!(
+            s.contains(
+                "| [Day 1](./src/bin/2025_01.rs) | `-` | `10ms` | `20ms` | `-` | `12 allocs, 8.0 KiB total, 1.5 KiB peak` | `-` |"
+            ),
+            true
+        );
+    }
 }
@@ -0,0 +1,305 @@
+/// Machine-readable results that solution binaries hand back to `run_multi`.
+/// Every solved or attempted part is written as one JSON object per line to the
+/// file passed via `--emit-json <path>`.
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, heap::HeapStats};
+
+/// Version of the record format, bumped on breaking changes.
+pub const RECORD_VERSION: u8 = 2;
+
+/// Command-line flag that selects the file records are appended to.
+pub const EMIT_FLAG: &str = "--emit-json";
+
+/// Part number used for the record of the shared `parse` phase.
+pub const PARSE_PART: u8 = 0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    Solved,
+    Unsolved,
+}
+
+impl PartStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+        }
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(PartStatus::Solved),
+            "unsolved" => Ok(PartStatus::Unsolved),
+            x => Err(format!("Unknown part status `{x}`.")),
+        }
+    }
+}
+
+/// The result of running a single part of a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartRecord {
+    pub day: Day,
+    pub part: u8,
+    pub status: PartStatus,
+    pub answer: Option<String>,
+    pub samples: u128,
+    pub outliers: u128,
+    pub median: Duration,
+    pub std_dev: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    /// Heap usage of the first, unbenched run.
+    pub heap: HeapStats,
+    /// Name of the implementation, set when the variants of a part are benched.
+    pub variant: Option<String>,
+}
+
+impl PartRecord {
+    /// Appends the record as a single JSON line to the file at `path`.
+    pub fn append_to(&self, path: &Path) -> Result<(), io::Error> {
+        let json = JsonValue::from(self)
+            .stringify()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{json}")
+    }
+
+    /// Reads all records from the file at `path`. A missing file yields no records.
+    pub fn read_all(path: &Path) -> Result<Vec<Self>, String> {
+        let Ok(content) = fs::read_to_string(path) else {
+            return Ok(vec![]);
+        };
+
+        content
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(PartRecord::from_str)
+            .collect()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+fn nanos(duration: Duration) -> JsonValue {
+    JsonValue::Number(duration.as_nanos() as f64)
+}
+
+impl From<&PartRecord> for JsonValue {
+    fn from(value: &PartRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("version".into(), JsonValue::Number(RECORD_VERSION.into()));
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(value.part.into()));
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        {
+            map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+            map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+            map.insert(
+                "allocations".into(),
+                JsonValue::Number(value.heap.allocations as f64),
+            );
+            map.insert(
+                "total_bytes".into(),
+                JsonValue::Number(value.heap.total_bytes as f64),
+            );
+            map.insert(
+                "peak_bytes".into(),
+                JsonValue::Number(value.heap.peak_bytes as f64),
+            );
+        }
+
+        if let Some(variant) = &value.variant {
+            map.insert("variant".into(), JsonValue::String(variant.clone()));
+        }
+
+        map.insert("median_nanos".into(), nanos(value.median));
+        map.insert("std_dev_nanos".into(), nanos(value.std_dev));
+        map.insert("min_nanos".into(), nanos(value.min));
+        map.insert("max_nanos".into(), nanos(value.max));
+        map.insert("p95_nanos".into(), nanos(value.p95));
+        map.insert("p99_nanos".into(), nanos(value.p99));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl FromStr for PartRecord {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("record is not valid JSON."))?;
+        PartRecord::try_from(&json)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartRecord {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected record.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let duration = |key: &str| number(key).map(|x| Duration::from_nanos(x as u64));
+
+        let version = number("version")?;
+        if version != f64::from(RECORD_VERSION) {
+            return Err(format!("Unsupported record version {version}."));
+        }
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected record.day to be a Day struct.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected record.status to be a string.")?
+            .parse()?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.answer to be null or string.")?;
+
+        let variant = match json.get("variant") {
+            None => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .ok_or("Expected record.variant to be a string.")?
+                    .clone(),
+            ),
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(PartRecord {
+            day,
+            part: number("part")? as u8,
+            status,
+            answer: answer.cloned(),
+            samples: number("samples")? as u128,
+            outliers: number("outliers")? as u128,
+            median: duration("median_nanos")?,
+            std_dev: duration("std_dev_nanos")?,
+            min: duration("min_nanos")?,
+            max: duration("max_nanos")?,
+            p95: duration("p95_nanos")?,
+            p99: duration("p99_nanos")?,
+            heap: HeapStats {
+                allocations: number("allocations")? as u64,
+                total_bytes: number("total_bytes")? as u64,
+                peak_bytes: number("peak_bytes")? as u64,
+            },
+            variant,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PartRecord, PartStatus};
+    use crate::{day, template::heap::HeapStats};
+    use std::{str::FromStr, time::Duration};
+    use tinyjson::JsonValue;
+
+    fn get_mock_record() -> PartRecord {
+        PartRecord {
+            day: day!(9),
+            part: 2,
+            status: PartStatus::Solved,
+            answer: Some("42 (@ samples)\nline".into()),
+            samples: 100,
+            outliers: 3,
+            median: Duration::from_micros(15),
+            std_dev: Duration::from_nanos(120),
+            min: Duration::from_micros(14),
+            max: Duration::from_micros(18),
+            p95: Duration::from_micros(16),
+            p99: Duration::from_micros(17),
+            heap: HeapStats {
+                allocations: 12,
+                total_bytes: 4096,
+                peak_bytes: 1024,
+            },
+            variant: None,
+        }
+    }
+
+    #[test]
+    fn roundtrips_records() {
+        let record = get_mock_record();
+        let line = JsonValue::from(&record).stringify().unwrap();
+        assert_eq!(line.contains('\n'), false);
+        assert_eq!(PartRecord::from_str(&line).unwrap(), record);
+    }
+
+    #[test]
+    fn roundtrips_variant_records() {
+        let record = PartRecord {
+            variant: Some("part_two_bruteforce".into()),
+            ..get_mock_record()
+        };
+        let line = JsonValue::from(&record).stringify().unwrap();
+        assert_eq!(PartRecord::from_str(&line).unwrap(), record);
+    }
+
+    #[test]
+    fn handles_unsolved_records() {
+        let line = r#"{ "version": 2, "day": "03", "part": 1, "status": "unsolved", "answer": null, "samples": 1, "outliers": 0, "median_nanos": 10, "std_dev_nanos": 0, "min_nanos": 10, "max_nanos": 10, "p95_nanos": 10, "p99_nanos": 10, "allocations": 0, "total_bytes": 0, "peak_bytes": 0 }"#;
+        let record = PartRecord::from_str(line).unwrap();
+        assert_eq!(record.status, PartStatus::Unsolved);
+        assert_eq!(record.answer, None);
+        assert_eq!(record.median, Duration::from_nanos(10));
+    }
+
+    #[test]
+    fn rejects_unknown_versions() {
+        let line = r#"{ "version": 99, "day": "03", "part": 1 }"#;
+        assert_eq!(
+            PartRecord::from_str(line).err(),
+            Some("Unsupported record version 99.".into())
+        );
+    }
+}
@@ -0,0 +1,160 @@
+/// In-process registry of solutions.
+/// The `all` binary bundles every day as a module and runs the requested days
+/// without spawning one `cargo run` per day.
+use std::{
+    env,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    str::FromStr,
+};
+
+use crate::input_path;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, PuzzleId, Year, records::PartRecord};
+
+/// Name of the binary that bundles all registered days.
+pub const REGISTRY_BIN: &str = "all";
+
+/// A registered day, generated by the `solution!` macro as `SOLUTION`.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub id: PuzzleId,
+    pub solve: fn(&str) -> Vec<PartRecord>,
+}
+
+/// Runs the requested days of `solutions` in day order and returns their result records.
+///
+/// The year is selected with `--year <year>` and defaults to `AOC_YEAR`. Days are selected with
+/// one or more `--day <day>` arguments, all registered days of the year run otherwise.
+/// Days that are requested but not registered, or that have no input yet, are reported as not solved.
+pub fn run(solutions: &[Solution]) -> Vec<PartRecord> {
+    let args: Vec<String> = env::args().collect();
+
+    let year = match requested_year(&args) {
+        Some(year) => Ok(Some(year)),
+        None => Year::from_env(),
+    };
+
+    let year = match year {
+        Ok(Some(year)) => year,
+        Ok(None) => {
+            eprintln!("No year to run, pass `--year <year>` or set AOC_YEAR.");
+            return vec![];
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            return vec![];
+        }
+    };
+
+    let days_to_run = requested_days(&args);
+    let mut records = vec![];
+    let mut need_space = false;
+
+    for day in year.days() {
+        let id = PuzzleId::new(year, day);
+        let solution = solutions.iter().find(|s| s.id == id);
+
+        match &days_to_run {
+            Some(days) if !days.contains(&day) => continue,
+            None if solution.is_none() => continue,
+            _ => {}
+        }
+
+        if need_space {
+            println!();
+        }
+        need_space = true;
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let Some(solution) = solution else {
+            println!("Not solved.");
+            continue;
+        };
+
+        let input_path = format!(input_path!(), year, day);
+        if !Path::new(&input_path).exists() {
+            println!("Not solved.");
+            continue;
+        }
+
+        let input = crate::template::read_file_input("", id, "input.txt");
+
+        // NOTE: a panicking day must not take the remaining days down with it.
+        match panic::catch_unwind(AssertUnwindSafe(|| (solution.solve)(&input))) {
+            Ok(day_records) => records.extend(day_records),
+            Err(_) => eprintln!("Day {day} panicked."),
+        }
+    }
+
+    records
+}
+
+fn requested_year(args: &[String]) -> Option<Year> {
+    args.windows(2)
+        .find(|w| w[0] == "--year")
+        .and_then(|w| Year::from_str(&w[1]).ok())
+}
+
+fn requested_days(args: &[String]) -> Option<Vec<Day>> {
+    let days: Vec<Day> = args
+        .windows(2)
+        .filter(|w| w[0] == "--day")
+        .filter_map(|w| Day::from_str(&w[1]).ok())
+        .collect();
+
+    if days.is_empty() { None } else { Some(days) }
+}
+
+/// Declares the days bundled into the `all` binary and runs them in-process.
+///
+/// Every entry maps a module name to the path of a solution binary, relative to the calling file.
+/// `cargo scaffold` appends new days to the list automatically.
+#[macro_export]
+macro_rules! registry {
+    ($( $module:ident => $path:literal ),* $(,)?) => {
+        $(
+            #[cfg(not(test))]
+            #[allow(dead_code)]
+            #[path = $path]
+            mod $module;
+        )*
+
+        #[cfg(not(test))]
+        fn main() {
+            $crate::template::registry::run(&[$( $module::SOLUTION ),*]);
+        }
+
+        #[cfg(test)]
+        fn main() {}
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{requested_days, requested_year};
+    use crate::{day, year};
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn parses_requested_days() {
+        let days = requested_days(&args(&["all", "--day", "03", "--time", "--day", "12"]));
+        assert_eq!(days, Some(vec![day!(3), day!(12)]));
+    }
+
+    #[test]
+    fn parses_requested_year() {
+        let year = requested_year(&args(&["all", "--year", "2016", "--day", "03"]));
+        assert_eq!(year, Some(year!(2016)));
+        assert_eq!(requested_year(&args(&["all", "--day", "03"])), None);
+    }
+
+    #[test]
+    fn runs_all_days_without_filter() {
+        assert_eq!(requested_days(&args(&["all", "--time"])), None);
+    }
+}
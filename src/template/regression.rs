@@ -0,0 +1,225 @@
+/// Module that compares fresh benchmarks with the timings stored in `data/<year>/timings.json`.
+use std::time::Duration;
+
+use crate::template::timings::{Timing, Timings};
+use crate::template::{ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET, Day};
+
+/// Default regression threshold of `cargo time --check`, in percent.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// The change of a single day and part between the stored and the fresh timings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartDelta {
+    pub day: Day,
+    pub part: &'static str,
+    pub stored: Duration,
+    /// `None` when the part did not finish in the fresh run.
+    pub fresh: Option<Duration>,
+}
+
+impl PartDelta {
+    /// Relative change in percent, positive values are slowdowns.
+    #[must_use]
+    pub fn change(&self) -> Option<f64> {
+        let stored = self.stored.as_secs_f64();
+        let fresh = self.fresh?.as_secs_f64();
+
+        if stored == 0.0 {
+            return None;
+        }
+
+        Some((fresh - stored) / stored * 100.0)
+    }
+
+    /// Whether the part got slower than `threshold` percent, or did not finish at all.
+    #[must_use]
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        match self.fresh {
+            None => true,
+            Some(_) => self.change().is_some_and(|change| change > threshold),
+        }
+    }
+}
+
+/// Parses a threshold in percent, with or without the trailing `%`.
+pub fn parse_threshold(s: &str) -> Result<f64, String> {
+    s.trim_end_matches('%')
+        .parse::<f64>()
+        .ok()
+        .filter(|x| x.is_finite() && *x >= 0.0)
+        .ok_or_else(|| format!("expecting a percentage like `10%`, got `{s}`"))
+}
+
+/// Parses a duration as printed by `{:.1?}`, e.g. `492.2µs` or `1.3s`.
+#[must_use]
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    let units = [
+        ("ns", 1e-9),
+        ("µs", 1e-6),
+        ("us", 1e-6),
+        ("ms", 1e-3),
+        ("s", 1.0),
+    ];
+
+    let (value, scale) = units
+        .iter()
+        .find_map(|(unit, scale)| s.trim().strip_suffix(unit).map(|value| (value, scale)))?;
+
+    let value = value.parse::<f64>().ok()?;
+    Duration::try_from_secs_f64(value * scale).ok()
+}
+
+fn parts(timing: &Timing) -> [(&'static str, Option<&String>); 3] {
+    [
+        ("Parse", timing.parse.as_ref()),
+        ("Part 1", timing.part_1.as_ref()),
+        ("Part 2", timing.part_2.as_ref()),
+    ]
+}
+
+/// Pairs every stored part of the fresh days with its fresh median.
+/// Days that were not run freshly are skipped.
+#[must_use]
+pub fn compare(stored: &Timings, fresh: &Timings) -> Vec<PartDelta> {
+    let mut deltas = vec![];
+
+    for fresh_timing in &fresh.data {
+        let Some(stored_timing) = stored.data.iter().find(|t| t.day == fresh_timing.day) else {
+            continue;
+        };
+
+        for ((part, stored), (_, fresh)) in
+            parts(stored_timing).into_iter().zip(parts(fresh_timing))
+        {
+            let Some(stored) = stored.and_then(|x| parse_duration(x)) else {
+                continue;
+            };
+
+            deltas.push(PartDelta {
+                day: fresh_timing.day,
+                part,
+                stored,
+                fresh: fresh.and_then(|x| parse_duration(x)),
+            });
+        }
+    }
+
+    deltas
+}
+
+fn format_change(delta: &PartDelta, threshold: f64) -> String {
+    let Some(change) = delta.change() else {
+        return match delta.fresh {
+            None => format!("{ANSI_RED}missing{ANSI_RESET}"),
+            Some(_) => "-".into(),
+        };
+    };
+
+    let str = format!("{change:>+7.1}%");
+
+    if delta.is_regression(threshold) {
+        format!("{ANSI_RED}{str}{ANSI_RESET}")
+    } else if change < -threshold {
+        format!("{ANSI_GREEN}{str}{ANSI_RESET}")
+    } else {
+        str
+    }
+}
+
+/// Prints the deltas as a table and returns the number of regressed parts.
+pub fn print_report(deltas: &[PartDelta], threshold: f64) -> usize {
+    println!(
+        "{ANSI_BOLD}{:<5} {:<8} {:>10} {:>10} {:>8}{ANSI_RESET}",
+        "Day", "Part", "Stored", "Fresh", "Delta"
+    );
+
+    for delta in deltas {
+        let fresh = delta
+            .fresh
+            .map_or_else(|| "-".into(), |fresh| format!("{fresh:.1?}"));
+
+        println!(
+            "{:<5} {:<8} {:>10} {:>10} {}",
+            delta.day.to_string(),
+            delta.part,
+            format!("{:.1?}", delta.stored),
+            fresh,
+            format_change(delta, threshold)
+        );
+    }
+
+    deltas
+        .iter()
+        .filter(|delta| delta.is_regression(threshold))
+        .count()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PartDelta, compare, parse_duration, parse_threshold};
+    use crate::{
+        day,
+        template::timings::{Timing, Timings},
+    };
+    use std::time::Duration;
+
+    fn get_mock_timing(part_1: Option<&str>, part_2: Option<&str>) -> Timing {
+        Timing {
+            part_1: part_1.map(Into::into),
+            part_2: part_2.map(Into::into),
+            ..Timing::new(day!(1))
+        }
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("12.0ns"), Some(Duration::from_nanos(12)));
+        assert_eq!(
+            parse_duration("492.5µs"),
+            Some(Duration::from_nanos(492_500))
+        );
+        assert_eq!(parse_duration("7.5ms"), Some(Duration::from_micros(7500)));
+        assert_eq!(parse_duration("1.5s"), Some(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("fast"), None);
+    }
+
+    #[test]
+    fn parses_thresholds() {
+        assert_eq!(parse_threshold("10%"), Ok(10.0));
+        assert_eq!(parse_threshold("2.5"), Ok(2.5));
+        assert!(parse_threshold("-5%").is_err());
+    }
+
+    #[test]
+    fn compares_timings() {
+        let stored = Timings {
+            data: vec![get_mock_timing(Some("10.0ms"), Some("20.0ms"))],
+        };
+        let fresh = Timings {
+            data: vec![get_mock_timing(Some("12.0ms"), None)],
+        };
+
+        let deltas = compare(&stored, &fresh);
+        assert_eq!(deltas.len(), 2);
+
+        assert_eq!(deltas[0].part, "Part 1");
+        assert_eq!((deltas[0].change().unwrap() - 20.0).abs() < 1e-9, true);
+        assert_eq!(deltas[0].is_regression(10.0), true);
+        assert_eq!(deltas[0].is_regression(25.0), false);
+
+        assert_eq!(deltas[1].part, "Part 2");
+        assert_eq!(deltas[1].fresh, None);
+        assert_eq!(deltas[1].is_regression(100.0), true);
+    }
+
+    #[test]
+    fn ignores_speedups() {
+        let delta = PartDelta {
+            day: day!(1),
+            part: "Part 1",
+            stored: Duration::from_millis(10),
+            fresh: Some(Duration::from_millis(5)),
+        };
+        assert_eq!(delta.is_regression(10.0), false);
+    }
+}
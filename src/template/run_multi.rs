@@ -1,41 +1,114 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    io,
+    path::Path,
+    sync::{
+        Once,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, PuzzleId, Year};
 
 use super::{
     all_days,
-    timings::{Timing, Timings},
+    records::PartRecord,
+    registry::REGISTRY_BIN,
+    runner::BenchConfig,
+    timings::{PartStats, Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+pub use child_commands::timing_from_records;
+use child_commands::{ChildExit, ChildRun};
+
+/// Options that apply to every day of a run.
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    pub is_release: bool,
+    pub is_timed: bool,
+    /// Number of days that run at the same time.
+    pub jobs: usize,
+    /// Kill a day that runs for longer than this.
+    pub timeout: Option<Duration>,
+    /// Kill a day when a single part (or the parse phase) runs for longer than this.
+    pub part_timeout: Option<Duration>,
+    /// Benchmark budget forwarded to timed runs.
+    pub bench: BenchConfig,
+    /// Run the variants registered with `variants!` instead of the parts.
+    pub variants: bool,
+}
 
-    let mut need_space = false;
+impl RunOptions {
+    fn has_timeout(&self) -> bool {
+        self.timeout.is_some() || self.part_timeout.is_some()
+    }
+}
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+/// Set once the user hits Ctrl-C. Children receive the signal themselves, we only stop starting new days.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+fn is_interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+/// Installs the Ctrl-C handler once, `run_days` may be called more than once per process.
+fn install_interrupt_handler() {
+    static INSTALL: Once = Once::new();
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    INSTALL.call_once(|| {
+        if let Err(e) = ctrlc::set_handler(|| INTERRUPTED.store(true, Ordering::SeqCst)) {
+            eprintln!("Failed to handle Ctrl-C, it stops the run right away: {e}");
+        }
+    });
+}
+
+/// Result records of a single day.
+pub struct DayRun {
+    pub day: Day,
+    pub records: Vec<PartRecord>,
+    /// Set when the day was killed before all of its parts finished.
+    pub timed_out: bool,
+    /// Set when Ctrl-C stopped the day, only the parts that finished before have records.
+    pub interrupted: bool,
+}
+
+impl DayRun {
+    /// The parts of an interrupted day that did not finish.
+    fn unfinished_parts(&self) -> Vec<u8> {
+        if !self.interrupted {
+            return vec![];
+        }
 
-    if is_timed {
-        let timings = Timings { data: timings };
+        [1, 2]
+            .into_iter()
+            .filter(|part| {
+                !self
+                    .records
+                    .iter()
+                    .any(|r| r.part == *part && r.variant.is_none())
+            })
+            .collect()
+    }
+}
+
+pub fn run_multi(year: Year, days_to_run: &HashSet<Day>, options: &RunOptions) -> Option<Timings> {
+    let runs = run_days(year, days_to_run, options);
+
+    if options.is_timed {
+        // NOTE: the finished parts of an interrupted day are not stored, the total only counts finished days.
+        let timings = Timings {
+            data: runs
+                .iter()
+                .filter(|run| run.unfinished_parts().is_empty())
+                .map(|run| Timing {
+                    timed_out: run.timed_out,
+                    ..child_commands::timing_from_records(&run.records, run.day)
+                })
+                .collect(),
+        };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
@@ -46,11 +119,241 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
     }
 }
 
+/// Run every variant of a day in its own bin and return their records.
+pub fn run_variants(id: PuzzleId, options: &RunOptions) -> Vec<PartRecord> {
+    let options = RunOptions {
+        variants: true,
+        ..options.clone()
+    };
+
+    match child_commands::run_solution(id, false, &options, false) {
+        Ok(run) => run.records,
+        Err(e) => {
+            eprintln!("Failed to run day {}: {e:?}", id.day);
+            vec![]
+        }
+    }
+}
+
+/// Run the requested days of `year` and return the result records of every day that reported any.
+pub fn run_days(year: Year, days_to_run: &HashSet<Day>, options: &RunOptions) -> Vec<DayRun> {
+    install_interrupt_handler();
+
+    let use_registry = Path::new(&get_path_for_registry()).exists();
+
+    let runs = if options.jobs > 1 {
+        run_parallel(year, days_to_run, use_registry, options)
+    } else if use_registry && !options.has_timeout() {
+        run_in_process(year, days_to_run, options)
+    } else {
+        run_serial(year, days_to_run, use_registry, options)
+    };
+
+    if is_interrupted() {
+        println!("\n{ANSI_BOLD}Interrupted.{ANSI_RESET} Remaining days were skipped.");
+    }
+
+    let unfinished: Vec<String> = runs
+        .iter()
+        .filter_map(|run| match run.unfinished_parts()[..] {
+            [] => None,
+            [part] => Some(format!("Day {} part {part}", run.day)),
+            _ => Some(format!("Day {} parts 1 and 2", run.day)),
+        })
+        .collect();
+
+    if !unfinished.is_empty() {
+        println!(
+            "\n{ANSI_BOLD}Unfinished:{ANSI_RESET} {}",
+            unfinished.join(", ")
+        );
+    }
+
+    let timed_out: Vec<String> = runs
+        .iter()
+        .filter(|run| run.timed_out)
+        .map(|run| format!("Day {}", run.day))
+        .collect();
+
+    if !timed_out.is_empty() {
+        println!(
+            "\n{ANSI_BOLD}Timed out:{ANSI_RESET} {}",
+            timed_out.join(", ")
+        );
+    }
+
+    runs
+}
+
+/// Run all requested days in a single invocation of the registry binary.
+fn run_in_process(year: Year, days_to_run: &HashSet<Day>, options: &RunOptions) -> Vec<DayRun> {
+    let run = match child_commands::run_registry(year, days_to_run, options) {
+        Ok(run) => run,
+        Err(e) => {
+            eprintln!("Failed to run solutions: {e:?}");
+            return vec![];
+        }
+    };
+
+    // NOTE: the last day that reported results may not have finished.
+    let incomplete_day = match run.exit {
+        ChildExit::Interrupted => run.records.last().map(|r| r.day),
+        _ => None,
+    };
+
+    all_days()
+        .filter(|day| days_to_run.contains(day))
+        .filter_map(|day| {
+            let records: Vec<PartRecord> = run
+                .records
+                .iter()
+                .filter(|r| r.day == day)
+                .cloned()
+                .collect();
+
+            if records.is_empty() {
+                None
+            } else {
+                Some(DayRun {
+                    day,
+                    records,
+                    timed_out: false,
+                    interrupted: Some(day) == incomplete_day,
+                })
+            }
+        })
+        .collect()
+}
+
+/// Run all requested days one after another, with one child process per day.
+fn run_serial(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    use_registry: bool,
+    options: &RunOptions,
+) -> Vec<DayRun> {
+    let mut runs: Vec<DayRun> = Vec::with_capacity(days_to_run.len());
+
+    let mut need_space = false;
+
+    // NOTE: use non-duplicate, sorted day values.
+    for day in all_days().filter(|day| days_to_run.contains(day)) {
+        if is_interrupted() {
+            break;
+        }
+
+        if need_space {
+            println!();
+        }
+        need_space = true;
+
+        let id = PuzzleId::new(year, day);
+        let run = child_commands::run_solution(id, use_registry, options, false);
+
+        if let Some(run) = collect_run(day, run) {
+            runs.push(run);
+        }
+    }
+
+    runs
+}
+
+/// Run up to `jobs` days at once. The output of each day is buffered and printed in day order.
+fn run_parallel(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    use_registry: bool,
+    options: &RunOptions,
+) -> Vec<DayRun> {
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+
+    let next_day = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    let mut runs: Vec<DayRun> = Vec::with_capacity(days.len());
+
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.min(days.len()) {
+            let tx = tx.clone();
+            let (days, next_day) = (&days, &next_day);
+
+            scope.spawn(move || {
+                while let Some(day) = days.get(next_day.fetch_add(1, Ordering::Relaxed)) {
+                    if is_interrupted() {
+                        break;
+                    }
+
+                    let id = PuzzleId::new(year, *day);
+                    let run = child_commands::run_solution(id, use_registry, options, true);
+
+                    if tx.send((*day, run)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        drop(tx);
+
+        // NOTE: days finish in any order, hold them back until all previous days were printed.
+        let mut pending = BTreeMap::new();
+        let mut to_print = days.iter().peekable();
+        let mut need_space = false;
+
+        for (day, run) in rx {
+            pending.insert(day, run);
+
+            while let Some(run) = to_print.peek().and_then(|day| pending.remove(*day)) {
+                let day = *to_print.next().unwrap();
+
+                if need_space {
+                    println!();
+                }
+                need_space = true;
+
+                if let Ok(run) = &run {
+                    run.stdout.iter().for_each(|line| println!("{line}"));
+                    run.stderr.iter().for_each(|line| eprintln!("{line}"));
+                }
+
+                if let Some(run) = collect_run(day, run) {
+                    runs.push(run);
+                }
+            }
+        }
+    });
+
+    runs
+}
+
+/// Turn the result of a single day into its records, if it produced any that are worth keeping.
+fn collect_run(day: Day, run: Result<ChildRun, Error>) -> Option<DayRun> {
+    let run = match run {
+        Ok(run) => run,
+        Err(e) => {
+            eprintln!("Failed to run day {day}: {e:?}");
+            return None;
+        }
+    };
+
+    match run.exit {
+        ChildExit::Completed | ChildExit::Interrupted if run.records.is_empty() => None,
+        exit => Some(DayRun {
+            day,
+            records: run.records,
+            timed_out: exit == ChildExit::TimedOut,
+            interrupted: exit == ChildExit::Interrupted,
+        }),
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
     IO(io::Error),
+    Records(String),
+    Build(String),
 }
 
 impl From<std::io::Error> for Error {
@@ -60,46 +363,218 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(id: PuzzleId) -> String {
+    format!("./src/bin/{id}.rs")
+}
+
+#[must_use]
+pub fn get_path_for_registry() -> String {
+    format!("./src/bin/{REGISTRY_BIN}.rs")
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting the result records they emit.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{Error, PartRecord, REGISTRY_BIN, RunOptions, get_path_for_bin, is_interrupted};
+    use crate::template::{
+        ANSI_BOLD, ANSI_RESET, Day, PuzzleId, Year,
+        records::{EMIT_FLAG, PARSE_PART, PartStatus},
+    };
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        collections::{HashMap, HashSet},
+        env, fs,
+        io::{BufRead, BufReader, Read},
+        path::{Path, PathBuf},
+        process::{self, Child, Command, Stdio},
+        str::FromStr,
+        sync::atomic::{AtomicUsize, Ordering},
         thread,
+        time::{Duration, Instant},
     };
+    use tinyjson::JsonValue;
+
+    /// How often a running child is checked for timeouts.
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ChildExit {
+        Completed,
+        TimedOut,
+        Interrupted,
+    }
+
+    /// Output of a finished child command. `stdout` and `stderr` are only collected when buffered.
+    pub struct ChildRun {
+        pub records: Vec<PartRecord>,
+        pub stdout: Vec<String>,
+        pub stderr: Vec<String>,
+        pub exit: ChildExit,
+    }
+
+    /// Run the solution of a given day, either through the registry bin or its own bin.
+    /// When `buffered` is set, output is collected instead of forwarded.
+    pub fn run_solution(
+        id: PuzzleId,
+        use_registry: bool,
+        options: &RunOptions,
+        buffered: bool,
+    ) -> Result<ChildRun, Error> {
+        let mut stdout = vec![];
+
+        let mut run = if use_registry {
+            let day_args = [
+                "--year".to_string(),
+                id.year.to_string(),
+                "--day".to_string(),
+                id.day.to_string(),
+            ];
+            run_bin(REGISTRY_BIN, &day_args, options, buffered)?
+        } else {
+            emit(
+                &mut stdout,
+                buffered,
+                format!("{ANSI_BOLD}Day {}{ANSI_RESET}", id.day),
+            );
+            emit(&mut stdout, buffered, "------".into());
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+            // skip command invocation for days that have not been scaffolded yet.
+            if Path::new(&get_path_for_bin(id)).exists() {
+                run_bin(&id.to_string(), &[], options, buffered)?
+            } else {
+                ChildRun {
+                    records: vec![],
+                    stdout: vec![],
+                    stderr: vec![],
+                    exit: ChildExit::Completed,
+                }
+            }
+        };
+
+        stdout.append(&mut run.stdout);
+
+        match run.exit {
+            ChildExit::TimedOut => emit(&mut stdout, buffered, "Timed out.".into()),
+            ChildExit::Completed if run.records.is_empty() && !use_registry => {
+                emit(&mut stdout, buffered, "Not solved.".into());
+            }
+            _ => {}
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        Ok(ChildRun { stdout, ..run })
+    }
+
+    /// Run the registry bin for a set of days of `year`
+    pub fn run_registry(
+        year: Year,
+        days: &HashSet<Day>,
+        options: &RunOptions,
+    ) -> Result<ChildRun, Error> {
+        let mut days: Vec<&Day> = days.iter().collect();
+        days.sort_unstable();
+
+        let day_args: Vec<String> = ["--year".to_string(), year.to_string()]
+            .into_iter()
+            .chain(
+                days.into_iter()
+                    .flat_map(|day| ["--day".to_string(), day.to_string()]),
+            )
+            .collect();
+
+        run_bin(REGISTRY_BIN, &day_args, options, false)
+    }
+
+    fn emit(lines: &mut Vec<String>, buffered: bool, line: String) {
+        if buffered {
+            lines.push(line);
+        } else {
+            println!("{line}");
+        }
+    }
+
+    /// Build a bin through cargo and return the path of its executable.
+    /// The executable is run directly afterwards so that it can be killed on timeout.
+    fn build_bin(bin: &str, is_release: bool) -> Result<PathBuf, Error> {
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--bin",
+            bin,
+            "--message-format=json-render-diagnostics",
+        ];
 
         if is_release {
             args.push("--release");
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        let mut cmd = Command::new("cargo")
+            .args(&args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+
+        let mut messages = String::new();
+        cmd.stdout
+            .take()
+            .ok_or(Error::BrokenPipe)?
+            .read_to_string(&mut messages)?;
+
+        if !cmd.wait()?.success() {
+            return Err(Error::Build(format!("failed to build bin `{bin}`.")));
+        }
+
+        messages
+            .lines()
+            .rev()
+            .filter_map(|line| JsonValue::from_str(line).ok())
+            .filter_map(|message| {
+                let message = message.get::<HashMap<String, JsonValue>>()?;
+                let target = message.get("target")?.get::<HashMap<String, JsonValue>>()?;
+
+                if target.get("name")?.get::<String>()? != bin {
+                    return None;
+                }
+
+                message
+                    .get("executable")?
+                    .get::<String>()
+                    .map(PathBuf::from)
+            })
+            .next()
+            .ok_or_else(|| Error::Build(format!("cargo did not report an executable for `{bin}`.")))
+    }
+
+    fn run_bin(
+        bin: &str,
+        bin_args: &[String],
+        options: &RunOptions,
+        buffered: bool,
+    ) -> Result<ChildRun, Error> {
+        let executable = build_bin(bin, options.is_release)?;
+
+        let records_path = get_records_path(bin);
+        // NOTE: records are appended, make sure a previous run does not leak into this one.
+        let _ = fs::remove_file(&records_path);
+
+        let mut args = vec![
+            EMIT_FLAG.to_string(),
+            records_path.to_string_lossy().to_string(),
+        ];
+        args.extend_from_slice(bin_args);
+
+        if options.is_timed {
+            // mirror `--time` flag and bench budget to child invocations.
+            args.push("--time".into());
+            args.extend(options.bench.to_args());
+        }
+
+        if options.variants {
+            args.push("--variants".into());
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward (or buffer) output, results are read from the records file.
 
-        let mut cmd = Command::new("cargo")
+        let mut cmd = Command::new(executable)
             .args(&args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -108,150 +583,250 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let stdout_thread = thread::spawn(move || {
+            let mut lines = vec![];
+            for line in stdout.lines().map_while(Result::ok) {
+                emit(&mut lines, buffered, line);
+            }
+            lines
+        });
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
+        let stderr_thread = thread::spawn(move || {
+            let mut lines = vec![];
+            for line in stderr.lines().map_while(Result::ok) {
+                if buffered {
+                    lines.push(line);
+                } else {
+                    eprintln!("{line}");
+                }
+            }
+            lines
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
+        let exit = wait_with_timeouts(&mut cmd, &records_path, options)?;
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let stdout_lines = stdout_thread.join().unwrap();
+        let stderr_lines = stderr_thread.join().unwrap();
 
-        Ok(output)
-    }
+        let records = PartRecord::read_all(&records_path).map_err(Error::Records);
+        let _ = fs::remove_file(&records_path);
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+        Ok(ChildRun {
+            records: records?,
+            stdout: stdout_lines,
+            stderr: stderr_lines,
+            exit,
+        })
+    }
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+    /// Wait for a child to exit, killing it once the day or its current part exceed their timeout.
+    /// A part is considered finished as soon as its record shows up in the records file.
+    fn wait_with_timeouts(
+        cmd: &mut Child,
+        records_path: &Path,
+        options: &RunOptions,
+    ) -> Result<ChildExit, Error> {
+        let day_start = Instant::now();
+        let mut part_start = day_start;
+        let mut records_len = 0;
+
+        loop {
+            if cmd.try_wait()?.is_some() {
+                return Ok(if is_interrupted() {
+                    ChildExit::Interrupted
+                } else {
+                    ChildExit::Completed
+                });
+            }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+            let len = fs::metadata(records_path).map_or(0, |m| m.len());
+            if len != records_len {
+                records_len = len;
+                part_start = Instant::now();
+            }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+            let day_expired = options.timeout.is_some_and(|t| day_start.elapsed() > t);
+            let part_expired = options
+                .part_timeout
+                .is_some_and(|t| part_start.elapsed() > t);
 
-                timings.total_nanos += nanos;
-            });
+            if day_expired || part_expired {
+                cmd.kill()?;
+                cmd.wait()?;
+                return Ok(ChildExit::TimedOut);
+            }
 
-        timings
+            thread::sleep(POLL_INTERVAL);
+        }
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    fn get_records_path(bin: &str) -> PathBuf {
+        // NOTE: the same bin may run several times at once in parallel mode.
+        static RUN_ID: AtomicUsize = AtomicUsize::new(0);
+        let run_id = RUN_ID.fetch_add(1, Ordering::Relaxed);
+        env::temp_dir().join(format!("aoc_{}_{run_id}_bin_{bin}.jsonl", process::id()))
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    fn format_duration(duration: Duration) -> String {
+        format!("{duration:.1?}")
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    pub fn timing_from_records(records: &[PartRecord], day: Day) -> super::Timing {
+        let mut timings = super::Timing::new(day);
+
+        // NOTE: untimed (single sample) runs are not benchmarks and are not recorded.
+        for record in records
+            .iter()
+            .filter(|r| r.status == PartStatus::Solved && r.samples > 1)
+        {
+            let median = Some(format_duration(record.median));
+            let stats = Some(super::PartStats {
+                std_dev: format_duration(record.std_dev),
+                min: format_duration(record.min),
+                max: format_duration(record.max),
+                p95: format_duration(record.p95),
+                p99: format_duration(record.p99),
+                #[allow(clippy::cast_possible_truncation)]
+                outliers: record.outliers as u64,
+            });
+
+            let heap = Some(record.heap);
+
+            match record.part {
+                PARSE_PART => {
+                    (timings.parse, timings.parse_stats, timings.parse_heap) =
+                        (median, stats, heap);
+                }
+                1 => {
+                    (timings.part_1, timings.part_1_stats, timings.part_1_heap) =
+                        (median, stats, heap)
+                }
+                2 => {
+                    (timings.part_2, timings.part_2_stats, timings.part_2_heap) =
+                        (median, stats, heap)
+                }
+                _ => continue,
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            {
+                timings.total_nanos += record.median.as_nanos() as f64;
+            }
+        }
+
+        timings
     }
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{ChildExit, ChildRun, timing_from_records};
+        use crate::template::run_multi::collect_run;
 
-        use crate::day;
+        use crate::{
+            day,
+            template::{
+                heap::HeapStats,
+                records::{PartRecord, PartStatus},
+            },
+        };
+        use std::time::Duration;
+
+        fn get_mock_record(part: u8, status: PartStatus, samples: u128) -> PartRecord {
+            PartRecord {
+                day: day!(1),
+                part,
+                status,
+                answer: None,
+                samples,
+                outliers: 2,
+                median: Duration::from_micros(1500),
+                std_dev: Duration::from_micros(20),
+                min: Duration::from_micros(1400),
+                max: Duration::from_micros(1800),
+                p95: Duration::from_micros(1600),
+                p99: Duration::from_micros(1700),
+                heap: HeapStats {
+                    allocations: 4,
+                    total_bytes: 2048,
+                    peak_bytes: 1024,
+                },
+                variant: None,
+            }
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn collects_timings_and_stats() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    get_mock_record(1, PartStatus::Solved, 100),
+                    get_mock_record(2, PartStatus::Solved, 10),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_eq!(res.total_nanos, 3_000_000_f64);
+            assert_eq!(res.part_1.unwrap(), "1.5ms");
+            assert_eq!(res.part_2.unwrap(), "1.5ms");
+
+            let stats = res.part_1_stats.unwrap();
+            assert_eq!(stats.std_dev, "20.0µs");
+            assert_eq!(stats.min, "1.4ms");
+            assert_eq!(stats.p95, "1.6ms");
+            assert_eq!(stats.p99, "1.7ms");
+            assert_eq!(stats.max, "1.8ms");
+            assert_eq!(stats.outliers, 2);
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
+        fn collects_parse_timings() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    get_mock_record(0, PartStatus::Solved, 100),
+                    get_mock_record(1, PartStatus::Solved, 100),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.total_nanos, 3_000_000_f64);
+            assert_eq!(res.parse.unwrap(), "1.5ms");
+            assert_eq!(res.parse_stats.unwrap().std_dev, "20.0µs");
+            assert_eq!(res.part_1.unwrap(), "1.5ms");
+            assert_eq!(res.part_2.is_none(), true);
+        }
+
+        #[test]
+        fn skips_untimed_parts() {
+            let res = timing_from_records(&[get_mock_record(1, PartStatus::Solved, 1)], day!(1));
+            assert_eq!(res.total_nanos, 0_f64);
+            assert_eq!(res.part_1.is_none(), true);
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
+        fn skips_missing_parts() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    get_mock_record(1, PartStatus::Unsolved, 100),
+                    get_mock_record(2, PartStatus::Unsolved, 100),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
+            assert_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.part_1_stats.is_none(), true);
+        }
+
+        #[test]
+        fn keeps_finished_parts_of_interrupted_days() {
+            let run = ChildRun {
+                records: vec![get_mock_record(1, PartStatus::Solved, 100)],
+                stdout: vec![],
+                stderr: vec![],
+                exit: ChildExit::Interrupted,
+            };
+
+            let run = collect_run(day!(1), Ok(run)).unwrap();
+            assert_eq!(run.records.len(), 1);
+            assert_eq!(run.interrupted, true);
+            assert_eq!(run.unfinished_parts(), vec![2]);
         }
     }
 }
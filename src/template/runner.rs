@@ -2,62 +2,204 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
+use std::path::Path;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, fs, process};
 
+use crate::advent_stdlib::ocr;
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answers::{self, Answers};
+use crate::template::aoc_cli::SubmitOutcome;
+use crate::template::backend::{self, PuzzleBackend};
+use crate::template::examples;
+use crate::template::heap::{self, HeapStats};
+use crate::template::input::InputSource;
+use crate::template::records::{EMIT_FLAG, PARSE_PART, PartRecord, PartStatus};
+use crate::template::submissions::{Refusal, Submission, SubmissionLog};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, PuzzleId};
+use crate::{answers_path, puzzle_path};
+
+pub fn run_part<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    id: PuzzleId,
+    part: u8,
+) -> PartRecord {
+    if examples::skips_part(part) {
+        return build_record(
+            None,
+            &BenchStats::default(),
+            &HeapStats::default(),
+            id.day,
+            part,
+        );
+    }
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, stats, heap) = run_timed(func, input, &format!("{}-{part}", id.day), |result| {
+        print_result(result, &part_str, "", None);
+    });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_result(&result, &part_str, &format_duration(&stats), Some(&heap));
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if stats.samples > 1 {
+        println!("{}", format_stats(&stats));
     }
+
+    let record = build_record(
+        result.as_ref().map(answer_text),
+        &stats,
+        &heap,
+        id.day,
+        part,
+    );
+    emit_record(&record);
+
+    // Answers of examples are never submitted.
+    if let Some(result) = result
+        && !examples::is_running()
+        && submit_part() == Some(part)
+    {
+        let args: Vec<String> = env::args().collect();
+        let source = InputSource::from_args(&args);
+
+        if !source.is_default() {
+            eprintln!("Not submitting part {part}: the answer is for the input from {source}.");
+            return record;
+        }
+
+        match answers::normalize(&answer_text(&result)) {
+            Ok(answer) => submit_result(&answer, id, part),
+            Err(e) => eprintln!("Not submitting part {part}: {e}"),
+        }
+    }
+
+    record
+}
+
+/// Run a named variant of a part, see `variants!`.
+/// Variants are timed like parts, but their answers are never submitted.
+pub fn run_variant<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    id: PuzzleId,
+    part: u8,
+    name: &str,
+) -> PartRecord {
+    let label = format!("Part {part} ({name})");
+
+    let (result, stats, heap) = run_timed(
+        func,
+        input,
+        &format!("{}-{part}-{name}", id.day),
+        |result| {
+            print_result(result, &label, "", None);
+        },
+    );
+
+    print_result(&result, &label, &format_duration(&stats), Some(&heap));
+
+    if stats.samples > 1 {
+        println!("{}", format_stats(&stats));
+    }
+
+    let record = PartRecord {
+        variant: Some(name.to_string()),
+        ..build_record(
+            result.as_ref().map(answer_text),
+            &stats,
+            &heap,
+            id.day,
+            part,
+        )
+    };
+    emit_record(&record);
+
+    record
+}
+
+/// The text of an answer. Letters drawn on a grid are read with OCR,
+/// so they are recorded and submitted like any other answer.
+fn answer_text<T: Display>(result: &T) -> String {
+    let answer = result.to_string();
+
+    if answer.trim().contains('\n') {
+        ocr::recognize(&answer).unwrap_or(answer)
+    } else {
+        answer
+    }
+}
+
+/// Run the shared `parse` function of a solution and time it separately from the parts.
+pub fn run_parse<I: Copy, T>(func: impl Fn(I) -> T, input: I, id: PuzzleId) -> (T, PartRecord) {
+    let done = Some('✔');
+
+    let (parsed, stats, heap) = run_timed(func, input, &format!("{}-parse", id.day), |_| {
+        print_result(&done, "Parse", "", None);
+    });
+
+    print_result(&done, "Parse", &format_duration(&stats), Some(&heap));
+
+    if stats.samples > 1 {
+        println!("{}", format_stats(&stats));
+    }
+
+    let record = build_record(Some(String::new()), &stats, &heap, id.day, PARSE_PART);
+    emit_record(&record);
+
+    (parsed, record)
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// The heap usage is measured on the first execution only, the benched samples run without
+/// counting allocations. `name` labels its DHAT report.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    name: &str,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, BenchStats, HeapStats) {
     let timer = Instant::now();
-    let result = {
-        #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
-
-        func(input)
-    };
+    let (result, heap) = heap::measure(name, || func(input));
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let args: Vec<String> = env::args().collect();
+
+    let stats = if args.iter().any(|x| x == "--time") {
+        let config = BenchConfig::from_args(&args).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            process::exit(1);
+        });
+        bench(func, input, &base_time, &config)
     } else {
-        (base_time, 1)
+        BenchStats::from_samples(vec![base_time])
     };
 
-    (result, run.0, run.1)
+    (result, stats, heap)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+) -> BenchStats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    for _ in 0..config.warmup() {
+        black_box(func(black_box(input)));
+    }
+
+    let bench_iterations = config.iterations(base_time);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -67,11 +209,222 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(timers)
+}
+
+pub const BENCH_TIME_FLAG: &str = "--bench-time";
+pub const MIN_SAMPLES_FLAG: &str = "--min-samples";
+pub const MAX_SAMPLES_FLAG: &str = "--max-samples";
+pub const WARMUP_FLAG: &str = "--warmup";
+
+const DEFAULT_BENCH_TIME: Duration = Duration::from_secs(1);
+const DEFAULT_MIN_SAMPLES: u128 = 10;
+const DEFAULT_MAX_SAMPLES: u128 = 10000;
+
+/// Budget of a benchmark run.
+///
+/// Values are taken from the `--bench-time`, `--min-samples`, `--max-samples` and `--warmup` flags,
+/// then from the `AOC_BENCH_TIME`, `AOC_BENCH_MIN_SAMPLES`, `AOC_BENCH_MAX_SAMPLES` and
+/// `AOC_BENCH_WARMUP` env vars. Unset values use the defaults (1 second, 10 to 10.000 samples, no warm-up),
+/// values that can not be parsed are an error.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BenchConfig {
+    pub bench_time: Option<Duration>,
+    pub min_samples: Option<u128>,
+    pub max_samples: Option<u128>,
+    pub warmup: Option<u128>,
+}
+
+impl BenchConfig {
+    /// Reads the config of a solution binary from its arguments, falling back to env vars.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let value = |flag: &str| {
+            args.iter()
+                .position(|x| x == flag)
+                .and_then(|i| args.get(i + 1).cloned())
+        };
+
+        let config = Self {
+            bench_time: parse_value(BENCH_TIME_FLAG, value(BENCH_TIME_FLAG), parse_seconds)?,
+            min_samples: parse_value(MIN_SAMPLES_FLAG, value(MIN_SAMPLES_FLAG), parse_count)?,
+            max_samples: parse_value(MAX_SAMPLES_FLAG, value(MAX_SAMPLES_FLAG), parse_count)?,
+            warmup: parse_value(WARMUP_FLAG, value(WARMUP_FLAG), parse_count)?,
+        };
+
+        Ok(config.or(Self::from_env()?))
+    }
+
+    /// Reads the config from the `AOC_BENCH_*` env vars.
+    pub fn from_env() -> Result<Self, String> {
+        let value = |var: &str| env::var(var).ok();
+
+        Ok(Self {
+            bench_time: parse_value("AOC_BENCH_TIME", value("AOC_BENCH_TIME"), parse_seconds)?,
+            min_samples: parse_value(
+                "AOC_BENCH_MIN_SAMPLES",
+                value("AOC_BENCH_MIN_SAMPLES"),
+                parse_count,
+            )?,
+            max_samples: parse_value(
+                "AOC_BENCH_MAX_SAMPLES",
+                value("AOC_BENCH_MAX_SAMPLES"),
+                parse_count,
+            )?,
+            warmup: parse_value("AOC_BENCH_WARMUP", value("AOC_BENCH_WARMUP"), parse_count)?,
+        })
+    }
+
+    /// The values of this config, falling back to those of `other`.
+    #[must_use]
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            bench_time: self.bench_time.or(other.bench_time),
+            min_samples: self.min_samples.or(other.min_samples),
+            max_samples: self.max_samples.or(other.max_samples),
+            warmup: self.warmup.or(other.warmup),
+        }
+    }
+
+    /// Arguments that forward this config to a solution binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        if let Some(bench_time) = self.bench_time {
+            args.extend([BENCH_TIME_FLAG.into(), bench_time.as_secs_f64().to_string()]);
+        }
+        if let Some(min_samples) = self.min_samples {
+            args.extend([MIN_SAMPLES_FLAG.into(), min_samples.to_string()]);
+        }
+        if let Some(max_samples) = self.max_samples {
+            args.extend([MAX_SAMPLES_FLAG.into(), max_samples.to_string()]);
+        }
+        if let Some(warmup) = self.warmup {
+            args.extend([WARMUP_FLAG.into(), warmup.to_string()]);
+        }
+
+        args
+    }
+
+    /// Number of measured iterations for a function that took `base_time` on its first run.
+    #[must_use]
+    pub fn iterations(&self, base_time: &Duration) -> u128 {
+        let bench_time = self.bench_time.unwrap_or(DEFAULT_BENCH_TIME);
+        let min_samples = self.min_samples.unwrap_or(DEFAULT_MIN_SAMPLES).max(1);
+        // NOTE: a min above the max wins, `clamp` would panic otherwise.
+        let max_samples = self
+            .max_samples
+            .unwrap_or(DEFAULT_MAX_SAMPLES)
+            .max(min_samples);
+
+        (bench_time.as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(min_samples, max_samples)
+    }
+
+    /// Number of unmeasured iterations before benching.
+    #[must_use]
+    pub fn warmup(&self) -> u128 {
+        self.warmup.unwrap_or(0)
+    }
+}
+
+/// Parses the value of a flag or env var called `name`, if it is set.
+fn parse_value<T>(
+    name: &str,
+    value: Option<String>,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Result<Option<T>, String> {
+    value
+        .map(|x| parse(&x).map_err(|e| format!("invalid value for {name}: {e}")))
+        .transpose()
+}
+
+fn parse_count(s: &str) -> Result<u128, String> {
+    s.parse()
+        .map_err(|_| format!("expecting a number, got `{s}`"))
+}
+
+/// Parses a (fractional) number of seconds, e.g. `2` or `0.5`.
+pub fn parse_seconds(s: &str) -> Result<Duration, String> {
+    s.parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| format!("expecting a number of seconds, got `{s}`"))
+}
+
+/// Summary statistics of a benchmark run.
+///
+/// Samples outside of the Tukey fences (1.5 × IQR beyond the quartiles) are
+/// rejected before any statistic is computed; `samples` counts every run,
+/// including the rejected ones.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BenchStats {
+    pub samples: u128,
+    pub outliers: u128,
+    pub mean: Duration,
+    pub median: Duration,
+    pub std_dev: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+}
+
+impl BenchStats {
+    /// Computes the statistics of a non-empty set of samples.
+    #[must_use]
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "cannot compute stats without samples");
+
+        samples.sort_unstable();
+        let total = samples.len();
+
+        // NOTE: quartiles are meaningless for a handful of samples, keep them all.
+        if total >= 4 {
+            let q1 = percentile(&samples, 25.0).as_nanos();
+            let q3 = percentile(&samples, 75.0).as_nanos();
+            let fence = (q3 - q1) * 3 / 2;
+            let (low, high) = (q1.saturating_sub(fence), q3 + fence);
+            samples.retain(|x| (low..=high).contains(&x.as_nanos()));
+        }
+
+        let mean = average_duration(&samples);
+        let variance = samples
+            .iter()
+            .map(|x| {
+                let delta = x.as_nanos().abs_diff(mean);
+                delta * delta
+            })
+            .sum::<u128>()
+            / samples.len() as u128;
+
+        Self {
+            samples: total as u128,
+            outliers: (total - samples.len()) as u128,
+            mean: nanos_to_duration(mean),
+            median: percentile(&samples, 50.0),
+            std_dev: nanos_to_duration(variance.isqrt()),
+            min: samples[0],
+            max: samples[samples.len() - 1],
+            p95: percentile(&samples, 95.0),
+            p99: percentile(&samples, 99.0),
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn nanos_to_duration(nanos: u128) -> Duration {
+    #[allow(clippy::cast_possible_truncation)]
+    Duration::from_nanos(nanos as u64)
 }
 
 fn average_duration(numbers: &[Duration]) -> u128 {
@@ -82,15 +435,30 @@ fn average_duration(numbers: &[Duration]) -> u128 {
         / numbers.len() as u128
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+fn format_duration(stats: &BenchStats) -> String {
+    if stats.samples == 1 {
+        format!(" ({:.1?})", stats.median)
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({:.1?} ± {:.1?} @ {} samples)",
+            stats.median, stats.std_dev, stats.samples
+        )
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn format_stats(stats: &BenchStats) -> String {
+    format!(
+        "  ↳ min {:.1?} · p95 {:.1?} · p99 {:.1?} · max {:.1?} · {} outliers",
+        stats.min, stats.p95, stats.p99, stats.max, stats.outliers
+    )
+}
+
+fn print_result<T: Display>(
+    result: &Option<T>,
+    part: &str,
+    duration_str: &str,
+    heap: Option<&HeapStats>,
+) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
@@ -102,7 +470,12 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 } else {
                     print!("\r");
                     println!("{str}");
+                    print_heap(heap);
                     println!("{result}");
+
+                    if let Some(text) = ocr::recognize(&result.to_string()) {
+                        println!("  ↳ reads {ANSI_BOLD}{text}{ANSI_RESET}");
+                    }
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
@@ -111,6 +484,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 } else {
                     print!("\r");
                     println!("{str}");
+                    print_heap(heap);
                 }
             }
         }
@@ -125,43 +499,273 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
+fn print_heap(heap: Option<&HeapStats>) {
+    if let Some(heap) = heap {
+        println!("  ↳ heap {}", heap.summary());
+    }
+}
+
+fn build_record(
+    answer: Option<String>,
+    stats: &BenchStats,
+    heap: &HeapStats,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> PartRecord {
+    PartRecord {
+        day,
+        part,
+        status: if answer.is_some() {
+            PartStatus::Solved
+        } else {
+            PartStatus::Unsolved
+        },
+        answer,
+        samples: stats.samples,
+        outliers: stats.outliers,
+        median: stats.median,
+        std_dev: stats.std_dev,
+        min: stats.min,
+        max: stats.max,
+        p95: stats.p95,
+        p99: stats.p99,
+        heap: *heap,
+        variant: None,
+    }
+}
+
+/// Append the outcome of a part to the file passed via `--emit-json <path>`, if any.
+fn emit_record(record: &PartRecord) {
     let args: Vec<String> = env::args().collect();
 
-    if !args.contains(&"--submit".into()) {
-        return None;
+    let Some(path) = args
+        .iter()
+        .position(|x| x == EMIT_FLAG)
+        .and_then(|i| args.get(i + 1))
+    else {
+        return;
+    };
+
+    if let Err(e) = record.append_to(Path::new(path)) {
+        eprintln!("Failed to emit result record: {e}");
     }
+}
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+/// Remember an accepted answer, so that `cargo verify` can check it later on.
+fn record_answer(id: PuzzleId, part: u8, answer: &str) {
+    match Answers::record(id, part, answer) {
+        Ok(()) => {
+            let path = format!(answers_path!(), id.year, id.day);
+            println!("Recorded answer in \"{path}\".");
+        }
+        Err(e) => eprintln!("Failed to record answer: {e}"),
+    }
+}
+
+/// Fetch the description again once part 1 is solved, it only shows part 2 from then on.
+fn refresh_puzzle(backend: &dyn PuzzleBackend, id: PuzzleId) {
+    let path = format!(puzzle_path!(), id.year, id.day);
+
+    match backend.description(id) {
+        Ok(description) => match fs::write(&path, description) {
+            Ok(()) => println!("Part 2 is unlocked, refreshed \"{path}\"."),
+            Err(e) => eprintln!("Failed to write puzzle: {e}"),
+        },
+        Err(e) => eprintln!("Failed to refresh puzzle: {e}"),
+    }
+}
+
+/// React to the verdict of the server on a submitted answer.
+fn handle_outcome(
+    backend: &dyn PuzzleBackend,
+    id: PuzzleId,
+    part: u8,
+    answer: &str,
+    outcome: &SubmitOutcome,
+) {
+    match outcome {
+        SubmitOutcome::Correct => {
+            record_answer(id, part, answer);
+            if part == 1 {
+                refresh_puzzle(backend, id);
+            }
+        }
+        SubmitOutcome::RateLimited { wait: Some(wait) } => {
+            println!("Rate limited, submit again in {}s.", wait.as_secs());
+        }
+        SubmitOutcome::RateLimited { wait: None } => {
+            println!("Rate limited, submit again later.");
+        }
+        SubmitOutcome::AlreadySolved => println!("Part {part} is already solved."),
+        SubmitOutcome::WrongLevel => {
+            println!(
+                "Part {part} can not be submitted: part 1 is not solved yet, or part {part} already is."
+            );
+        }
+        SubmitOutcome::Incorrect { .. } => {}
+    }
+
+    if let Some(submission) = Submission::from_outcome(part, answer, outcome) {
+        log_submission(id, submission);
+    }
+}
+
+/// Add a submission to the local history of the day.
+fn log_submission(id: PuzzleId, submission: Submission) {
+    let mut log = SubmissionLog::read(id).unwrap_or_else(|e| {
+        eprintln!("Failed to read submissions, starting a new history: {e}");
+        SubmissionLog::default()
+    });
+
+    log.data.push(submission);
+
+    if let Err(e) = log.store(id) {
+        eprintln!("Failed to store submission: {e}");
+    }
+}
+
+/// Check the local history before sending an answer to the server.
+fn check_submission(id: PuzzleId, part: u8, answer: &str) -> Result<(), Refusal> {
+    let answers = Answers::read(id).map_err(Refusal::Unreadable)?;
+    if answers.get(part).is_some() {
+        return Err(Refusal::AlreadySolved);
+    }
+
+    match SubmissionLog::read(id) {
+        Ok(log) => log.check(part, answer),
+        Err(e) => {
+            eprintln!("Failed to read submissions: {e}");
+            Ok(())
+        }
+    }
+}
+
+/// Try to submit the normalized answer of one part of the solution if:
+///  1. a backend is available, see `backend::from_env`.
+///  2. the local submission history does not rule the answer out.
+fn submit_result(answer: &str, id: PuzzleId, part: u8) {
+    if let Err(refusal) = check_submission(id, part, answer) {
+        eprintln!("Not submitting {answer}: {refusal}");
+        return;
+    }
+
+    let backend = backend::from_env().unwrap_or_else(|e| {
+        eprintln!("{e}");
         process::exit(1);
+    });
+
+    println!("Submitting result via {}...", backend.name());
+    match backend::submit(&*backend, id, part, answer) {
+        Ok(outcome) => handle_outcome(&*backend, id, part, answer, &outcome),
+        Err(e) => eprintln!("Failed to submit: {e}"),
     }
+}
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+/// The part passed to `--submit`, if any.
+fn submit_part() -> Option<u8> {
+    let args: Vec<String> = env::args().collect();
+    let part_index = args.iter().position(|x| x == "--submit")? + 1;
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
+    let Some(Ok(part)) = args.get(part_index).map(|x| x.parse::<u8>()) else {
         eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
         process::exit(1);
     };
 
-    if part_submit != part {
-        return None;
+    Some(part)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BenchConfig, BenchStats};
+    use std::time::Duration;
+
+    #[test]
+    fn uses_default_bench_budget() {
+        let config = BenchConfig::default();
+        assert_eq!(config.iterations(&Duration::from_millis(1)), 1000);
+        assert_eq!(config.iterations(&Duration::from_secs(1)), 10);
+        assert_eq!(config.iterations(&Duration::from_nanos(1)), 10000);
+        assert_eq!(config.warmup(), 0);
+    }
+
+    #[test]
+    fn uses_configured_bench_budget() {
+        let config = BenchConfig {
+            bench_time: Some(Duration::from_secs(2)),
+            min_samples: Some(50),
+            max_samples: Some(500),
+            warmup: Some(3),
+        };
+        assert_eq!(config.iterations(&Duration::from_millis(10)), 200);
+        assert_eq!(config.iterations(&Duration::from_secs(1)), 50);
+        assert_eq!(config.iterations(&Duration::from_micros(1)), 500);
+        assert_eq!(config.warmup(), 3);
+    }
+
+    #[test]
+    fn prefers_min_samples_over_max_samples() {
+        let config = BenchConfig {
+            min_samples: Some(100),
+            max_samples: Some(20),
+            ..Default::default()
+        };
+        assert_eq!(config.iterations(&Duration::from_secs(1)), 100);
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+    #[test]
+    fn roundtrips_bench_args() {
+        let config = BenchConfig {
+            bench_time: Some(Duration::from_millis(500)),
+            min_samples: Some(5),
+            max_samples: None,
+            warmup: Some(2),
+        };
+        let mut args = vec!["--time".to_string()];
+        args.extend(config.to_args());
+        assert_eq!(BenchConfig::from_args(&args), Ok(config));
+    }
+
+    #[test]
+    fn rejects_invalid_bench_args() {
+        let args = ["--min-samples".to_string(), "ten".to_string()];
+        assert_eq!(
+            BenchConfig::from_args(&args),
+            Err("invalid value for --min-samples: expecting a number, got `ten`".into())
         );
-        process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_micros(*x)).collect()
+    }
+
+    #[test]
+    fn computes_stats_for_single_sample() {
+        let stats = BenchStats::from_samples(micros(&[5]));
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.median, Duration::from_micros(5));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.p99, Duration::from_micros(5));
+    }
+
+    #[test]
+    fn computes_order_statistics() {
+        let stats = BenchStats::from_samples(micros(&[4, 1, 3, 2, 5]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.max, Duration::from_micros(5));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.mean, Duration::from_micros(3));
+        assert_eq!(stats.p95, Duration::from_micros(5));
+        assert_eq!(stats.std_dev, Duration::from_nanos(1414));
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = BenchStats::from_samples(micros(&[10, 10, 11, 9, 10, 12, 10, 5000]));
+        assert_eq!(stats.samples, 8);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.max, Duration::from_micros(12));
+        assert_eq!(stats.median, Duration::from_micros(10));
+    }
 }
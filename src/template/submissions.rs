@@ -0,0 +1,410 @@
+/// Local history of submitted answers, stored in `data/<year>/day_XX/submissions.json`.
+/// It keeps `--submit` from sending answers that are already known to be wrong.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs, io,
+    path::Path,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::submissions_path;
+use crate::template::PuzzleId;
+use crate::template::aoc_cli::{Hint, SubmitOutcome};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    /// The part was solved before, the answer itself was not judged.
+    AlreadySolved,
+}
+
+/// A single submitted answer and the verdict of the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub hint: Option<Hint>,
+    /// Unix timestamp in seconds.
+    pub submitted_at: u64,
+}
+
+impl Submission {
+    /// Turns the outcome of a submission into a log entry.
+    /// Outcomes without a verdict, e.g. because of rate limiting, yield `None`.
+    #[must_use]
+    pub fn from_outcome(part: u8, answer: &str, outcome: &SubmitOutcome) -> Option<Self> {
+        let (verdict, hint) = match outcome {
+            SubmitOutcome::Correct => (Verdict::Correct, None),
+            SubmitOutcome::Incorrect { hint } => (Verdict::Incorrect, *hint),
+            SubmitOutcome::AlreadySolved => (Verdict::AlreadySolved, None),
+            _ => return None,
+        };
+
+        let submitted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        Some(Submission {
+            part,
+            answer: answer.to_string(),
+            verdict,
+            hint,
+            submitted_at,
+        })
+    }
+}
+
+/// Reason to not send an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved,
+    KnownWrong(Option<Hint>),
+    /// The answer is at or above an answer that was too high.
+    AboveBound(i128),
+    /// The answer is at or below an answer that was too low.
+    BelowBound(i128),
+    /// The recorded answers can not be read, so the answer can not be checked.
+    Unreadable(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved => write!(f, "this part is already solved."),
+            Refusal::KnownWrong(Some(Hint::TooHigh)) => {
+                write!(f, "this answer was already submitted and is too high.")
+            }
+            Refusal::KnownWrong(Some(Hint::TooLow)) => {
+                write!(f, "this answer was already submitted and is too low.")
+            }
+            Refusal::KnownWrong(None) => {
+                write!(f, "this answer was already submitted and is wrong.")
+            }
+            Refusal::AboveBound(bound) => {
+                write!(f, "{bound} was already too high, the answer must be lower.")
+            }
+            Refusal::BelowBound(bound) => {
+                write!(f, "{bound} was already too low, the answer must be higher.")
+            }
+            Refusal::Unreadable(e) => write!(f, "failed to read the known answers: {e}"),
+        }
+    }
+}
+
+/// All submissions of a single day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SubmissionLog {
+    pub data: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// Reads the submissions of a puzzle. A missing file yields an empty log.
+    pub fn read(id: PuzzleId) -> Result<Self, String> {
+        let path = format!(submissions_path!(), id.year, id.day);
+
+        if !Path::new(&path).exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        SubmissionLog::from_str(&content).map_err(|e| format!("{path}: {e}"))
+    }
+
+    /// Writes the submissions of a puzzle, overwriting the previous file.
+    pub fn store(&self, id: PuzzleId) -> Result<(), io::Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(format!(submissions_path!(), id.year, id.day))?;
+        json.format_to(&mut file)
+    }
+
+    /// Checks whether `answer` is worth sending for `part`.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), Refusal> {
+        let submissions = self.data.iter().filter(|s| s.part == part);
+
+        if submissions
+            .clone()
+            .any(|s| matches!(s.verdict, Verdict::Correct | Verdict::AlreadySolved))
+        {
+            return Err(Refusal::AlreadySolved);
+        }
+
+        if let Some(known) = submissions
+            .clone()
+            .find(|s| s.answer.trim() == answer.trim())
+        {
+            return Err(Refusal::KnownWrong(known.hint));
+        }
+
+        let Ok(value) = answer.trim().parse::<i128>() else {
+            return Ok(());
+        };
+
+        let (lower, upper) = self.bounds(part);
+
+        match (lower, upper) {
+            (_, Some(upper)) if value >= upper => Err(Refusal::AboveBound(upper)),
+            (Some(lower), _) if value <= lower => Err(Refusal::BelowBound(lower)),
+            _ => Ok(()),
+        }
+    }
+
+    /// The highest answer that was too low and the lowest answer that was too high.
+    #[must_use]
+    pub fn bounds(&self, part: u8) -> (Option<i128>, Option<i128>) {
+        let hinted = |hint: Hint| {
+            self.data
+                .iter()
+                .filter(move |s| s.part == part && s.hint == Some(hint))
+                .filter_map(|s| s.answer.trim().parse::<i128>().ok())
+        };
+
+        (hinted(Hint::TooLow).max(), hinted(Hint::TooHigh).min())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Verdict {
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect => "incorrect",
+            Verdict::AlreadySolved => "already_solved",
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "incorrect" => Ok(Verdict::Incorrect),
+            "already_solved" => Ok(Verdict::AlreadySolved),
+            x => Err(format!("Unknown verdict `{x}`.")),
+        }
+    }
+}
+
+impl Hint {
+    fn as_str(self) -> &'static str {
+        match self {
+            Hint::TooHigh => "too_high",
+            Hint::TooLow => "too_low",
+        }
+    }
+}
+
+impl FromStr for Hint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "too_high" => Ok(Hint::TooHigh),
+            "too_low" => Ok(Hint::TooLow),
+            x => Err(format!("Unknown hint `{x}`.")),
+        }
+    }
+}
+
+impl From<&SubmissionLog> for JsonValue {
+    fn from(value: &SubmissionLog) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl FromStr for SubmissionLog {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("submissions are not valid JSON."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submissions to be a JSON object.")?
+            .get("data")
+            .ok_or("Expected submissions to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("Expected `submissions.data` to be an array.")?;
+
+        Ok(SubmissionLog {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(value.part.into()));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.as_str().into()),
+        );
+        map.insert(
+            "hint".into(),
+            value
+                .hint
+                .map_or(JsonValue::Null, |h| JsonValue::String(h.as_str().into())),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "submitted_at".into(),
+            JsonValue::Number(value.submitted_at as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .ok_or(format!("Expected submission.{key} to be a string."))
+        };
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected submission.{key} to be a number."))
+        };
+
+        let hint = match json.get("hint") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .ok_or("Expected submission.hint to be null or string.")?
+                    .parse()?,
+            ),
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Submission {
+            part: number("part")? as u8,
+            answer: string("answer")?.clone(),
+            verdict: string("verdict")?.parse()?,
+            hint,
+            submitted_at: number("submitted_at")? as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Refusal, Submission, SubmissionLog, Verdict};
+    use crate::template::aoc_cli::{Hint, SubmitOutcome};
+    use std::str::FromStr;
+    use tinyjson::JsonValue;
+
+    fn submission(part: u8, answer: &str, verdict: Verdict, hint: Option<Hint>) -> Submission {
+        Submission {
+            part,
+            answer: answer.into(),
+            verdict,
+            hint,
+            submitted_at: 1_733_011_200,
+        }
+    }
+
+    fn get_mock_log() -> SubmissionLog {
+        SubmissionLog {
+            data: vec![
+                submission(1, "100", Verdict::Incorrect, Some(Hint::TooLow)),
+                submission(1, "500", Verdict::Incorrect, Some(Hint::TooHigh)),
+                submission(1, "300", Verdict::Incorrect, None),
+                submission(2, "42", Verdict::Correct, None),
+            ],
+        }
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let log = get_mock_log();
+        let json = JsonValue::from(&log).stringify().unwrap();
+        assert_eq!(SubmissionLog::from_str(&json).unwrap(), log);
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        assert_eq!(get_mock_log().check(2, "43"), Err(Refusal::AlreadySolved));
+
+        let log = SubmissionLog {
+            data: vec![submission(1, "7", Verdict::AlreadySolved, None)],
+        };
+        assert_eq!(log.check(1, "8"), Err(Refusal::AlreadySolved));
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let log = get_mock_log();
+        assert_eq!(log.check(1, "300"), Err(Refusal::KnownWrong(None)));
+        assert_eq!(
+            log.check(1, "500"),
+            Err(Refusal::KnownWrong(Some(Hint::TooHigh)))
+        );
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let log = get_mock_log();
+        assert_eq!(log.bounds(1), (Some(100), Some(500)));
+        assert_eq!(log.check(1, "600"), Err(Refusal::AboveBound(500)));
+        assert_eq!(log.check(1, "50"), Err(Refusal::BelowBound(100)));
+        assert_eq!(log.check(1, "250"), Ok(()));
+        assert_eq!(log.check(1, "ABC"), Ok(()));
+    }
+
+    #[test]
+    fn logs_verdicts_of_outcomes() {
+        let correct = Submission::from_outcome(1, "42", &SubmitOutcome::Correct).unwrap();
+        assert_eq!(correct.verdict, Verdict::Correct);
+
+        let too_high = Submission::from_outcome(
+            1,
+            "42",
+            &SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+            },
+        )
+        .unwrap();
+        assert_eq!(too_high.verdict, Verdict::Incorrect);
+        assert_eq!(too_high.hint, Some(Hint::TooHigh));
+
+        let already_solved =
+            Submission::from_outcome(1, "42", &SubmitOutcome::AlreadySolved).unwrap();
+        assert_eq!(already_solved.verdict, Verdict::AlreadySolved);
+
+        let rate_limited =
+            Submission::from_outcome(1, "42", &SubmitOutcome::RateLimited { wait: None });
+        assert_eq!(rate_limited, None);
+    }
+}
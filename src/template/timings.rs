@@ -1,17 +1,95 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{Day, Year, heap::HeapStats, regression::parse_duration};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+/// Timings are stored per year, next to the days they belong to.
+fn get_path(year: Year) -> String {
+    format!("./data/{year}/timings.json")
+}
 
 /// Represents benchmark times for a single day.
+/// `parse`, `part_1` and `part_2` hold the median duration of each phase.
+/// `parse` is only present for solutions that declare a shared `parse` function.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
+    pub parse: Option<String>,
+    pub parse_stats: Option<PartStats>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_stats: Option<PartStats>,
+    pub part_2_stats: Option<PartStats>,
+    /// Heap usage of the first run of each phase.
+    pub parse_heap: Option<HeapStats>,
+    pub part_1_heap: Option<HeapStats>,
+    pub part_2_heap: Option<HeapStats>,
     pub total_nanos: f64,
+    /// Set when the day was killed before all of its parts finished.
+    pub timed_out: bool,
+}
+
+impl Timing {
+    /// A timing of `day` without any benched phase.
+    #[must_use]
+    pub fn new(day: Day) -> Self {
+        Timing {
+            day,
+            parse: None,
+            parse_stats: None,
+            part_1: None,
+            part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            parse_heap: None,
+            part_1_heap: None,
+            part_2_heap: None,
+            total_nanos: 0_f64,
+            timed_out: false,
+        }
+    }
+
+    /// Replace the phases that were benched in `new`, keeping the others.
+    #[must_use]
+    pub fn merge_phases(&self, new: &Timing) -> Timing {
+        let mut merged = Timing {
+            parse: new.parse.clone().or_else(|| self.parse.clone()),
+            parse_stats: new.parse_stats.clone().or_else(|| self.parse_stats.clone()),
+            parse_heap: new.parse_heap.or(self.parse_heap),
+            part_1: new.part_1.clone().or_else(|| self.part_1.clone()),
+            part_1_stats: new
+                .part_1_stats
+                .clone()
+                .or_else(|| self.part_1_stats.clone()),
+            part_1_heap: new.part_1_heap.or(self.part_1_heap),
+            part_2: new.part_2.clone().or_else(|| self.part_2.clone()),
+            part_2_stats: new
+                .part_2_stats
+                .clone()
+                .or_else(|| self.part_2_stats.clone()),
+            part_2_heap: new.part_2_heap.or(self.part_2_heap),
+            ..self.clone()
+        };
+
+        merged.total_nanos = [&merged.parse, &merged.part_1, &merged.part_2]
+            .into_iter()
+            .filter_map(|median| median.as_deref().and_then(parse_duration))
+            .map(|median| median.as_secs_f64() * 1e9)
+            .sum();
+
+        merged
+    }
+}
+
+/// Represents the spread of a benched part around its median.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PartStats {
+    pub std_dev: String,
+    pub min: String,
+    pub max: String,
+    pub p95: String,
+    pub p99: String,
+    pub outliers: u64,
 }
 
 /// Represents benchmark times for a set of days.
@@ -22,16 +100,16 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate the timings of `year` to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(get_path(year))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    /// Rehydrate the timings of `year` from a JSON file. If not present, returns empty timings.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,7 +129,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -113,6 +191,23 @@ impl From<&Timing> for JsonValue {
 
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
+        map.insert("timed_out".into(), JsonValue::Boolean(value.timed_out));
+
+        map.insert(
+            "parse".into(),
+            value
+                .parse
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "parse_stats".into(),
+            value
+                .parse_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
 
         let part_1 = value.part_1.clone().map(JsonValue::String);
         let part_2 = value.part_2.clone().map(JsonValue::String);
@@ -133,6 +228,33 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "part_1_stats".into(),
+            value
+                .part_1_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_2_stats".into(),
+            value
+                .part_2_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        for (key, heap) in [
+            ("parse_heap", &value.parse_heap),
+            ("part_1_heap", &value.part_1_heap),
+            ("part_2_heap", &value.part_2_heap),
+        ] {
+            map.insert(
+                key.into(),
+                heap.as_ref().map_or(JsonValue::Null, JsonValue::from),
+            );
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -166,15 +288,160 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: parse and stats are optional to stay compatible with timings stored before they existed.
+        let parse = match json.get("parse") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .cloned()
+                    .ok_or("Expected timing.parse to be null or string.")?,
+            ),
+        };
+
+        let timed_out = match json.get("timed_out") {
+            None => false,
+            Some(v) => *v
+                .get::<bool>()
+                .ok_or("Expected timing.timed_out to be a boolean.")?,
+        };
+
+        let parse_stats = optional_stats(json.get("parse_stats"))?;
+        let part_1_stats = optional_stats(json.get("part_1_stats"))?;
+        let part_2_stats = optional_stats(json.get("part_2_stats"))?;
+        let parse_heap = optional_heap(json.get("parse_heap"))?;
+        let part_1_heap = optional_heap(json.get("part_1_heap"))?;
+        let part_2_heap = optional_heap(json.get("part_2_heap"))?;
+
         Ok(Timing {
             day,
+            parse,
+            parse_stats,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_stats,
+            part_2_stats,
+            parse_heap,
+            part_1_heap,
+            part_2_heap,
             total_nanos,
+            timed_out,
         })
     }
 }
 
+fn optional_stats(value: Option<&JsonValue>) -> Result<Option<PartStats>, String> {
+    match value {
+        None => Ok(None),
+        Some(v) if v.is_null() => Ok(None),
+        Some(v) => PartStats::try_from(v).map(Some),
+    }
+}
+
+fn optional_heap(value: Option<&JsonValue>) -> Result<Option<HeapStats>, String> {
+    match value {
+        None => Ok(None),
+        Some(v) if v.is_null() => Ok(None),
+        Some(v) => heap_from_json(v).map(Some),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartStats> for JsonValue {
+    fn from(value: &PartStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("std_dev".into(), JsonValue::String(value.std_dev.clone()));
+        map.insert("min".into(), JsonValue::String(value.min.clone()));
+        map.insert("max".into(), JsonValue::String(value.max.clone()));
+        map.insert("p95".into(), JsonValue::String(value.p95.clone()));
+        map.insert("p99".into(), JsonValue::String(value.p99.clone()));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part stats to be a JSON object.")?;
+
+        let get_string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .cloned()
+                .ok_or(format!("Expected stats.{key} to be a string."))
+        };
+
+        let outliers = json
+            .get("outliers")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected stats.outliers to be a number.")?;
+
+        Ok(PartStats {
+            std_dev: get_string("std_dev")?,
+            min: get_string("min")?,
+            max: get_string("max")?,
+            p95: get_string("p95")?,
+            p99: get_string("p99")?,
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            outliers: outliers as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HeapStats> for JsonValue {
+    fn from(value: &HeapStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        {
+            map.insert(
+                "allocations".into(),
+                JsonValue::Number(value.allocations as f64),
+            );
+            map.insert(
+                "total_bytes".into(),
+                JsonValue::Number(value.total_bytes as f64),
+            );
+            map.insert(
+                "peak_bytes".into(),
+                JsonValue::Number(value.peak_bytes as f64),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+fn heap_from_json(value: &JsonValue) -> Result<HeapStats, String> {
+    let json = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("Expected heap stats to be a JSON object.")?;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let number = |key: &str| {
+        json.get(key)
+            .and_then(|v| v.get::<f64>().copied())
+            .map(|x| x as u64)
+            .ok_or(format!("Expected heap.{key} to be a number."))
+    };
+
+    Ok(HeapStats {
+        allocations: number("allocations")?,
+        total_bytes: number("total_bytes")?,
+        peak_bytes: number("peak_bytes")?,
+    })
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
@@ -187,22 +454,21 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
-                    part_2: None,
                     total_nanos: 4e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -223,6 +489,26 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "std_dev": "1µs", "min": "0.9ms", "max": "1.2ms", "p95": "1.1ms", "p99": "1.2ms", "outliers": 3 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.as_ref().unwrap();
+            assert_eq!(stats.std_dev, "1µs");
+            assert_eq!(stats.p99, "1.2ms");
+            assert_eq!(stats.outliers, 3);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
+        #[test]
+        fn handles_timed_out_days() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "timed_out": true }, { "day": "02", "part_1": null, "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].timed_out, true);
+            assert_eq!(timings.data[1].timed_out, false);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -247,9 +533,48 @@ mod tests {
 
     mod serialization {
         use super::get_mock_timings;
+        use crate::template::{
+            heap::HeapStats,
+            timings::{PartStats, Timings},
+        };
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
+        #[test]
+        fn roundtrips_stats() {
+            let mut timings = get_mock_timings();
+            let stats = PartStats {
+                std_dev: "1ms".into(),
+                min: "9ms".into(),
+                max: "12ms".into(),
+                p95: "11ms".into(),
+                p99: "12ms".into(),
+                outliers: 1,
+            };
+            timings.data[0].part_1_stats = Some(stats.clone());
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].part_1_stats, Some(stats));
+            assert_eq!(parsed.data[0].part_2_stats, None);
+        }
+
+        #[test]
+        fn roundtrips_heap_stats() {
+            let mut timings = get_mock_timings();
+            let heap = HeapStats {
+                allocations: 12,
+                total_bytes: 4096,
+                peak_bytes: 1024,
+            };
+            timings.data[1].part_2_heap = Some(heap);
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[1].part_2_heap, Some(heap));
+            assert_eq!(parsed.data[1].part_1_heap, None);
+        }
+
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
@@ -278,42 +603,36 @@ mod tests {
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
-                    part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
-                }],
+                data: vec![Timing::new(day!(1))],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -329,12 +648,7 @@ mod tests {
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(day!(3))],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -349,12 +663,7 @@ mod tests {
             let timings = get_mock_timings();
 
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(day!(2))],
             };
             let merged = timings.merge(&other);
 
@@ -380,5 +689,22 @@ mod tests {
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 3);
         }
+
+        #[test]
+        fn merges_benched_phases() {
+            let stored = &get_mock_timings().data[0];
+
+            let fresh = Timing {
+                part_1: None,
+                part_2: Some("5.0ms".into()),
+                total_nanos: 5e+6,
+                ..stored.clone()
+            };
+            let merged = stored.merge_phases(&fresh);
+
+            assert_eq!(merged.part_1, Some("10ms".into()));
+            assert_eq!(merged.part_2, Some("5.0ms".into()));
+            assert_eq!(merged.total_nanos, 1.5e+7);
+        }
     }
 }
@@ -0,0 +1,417 @@
+/// Alternate implementations of the parts of a day, registered with `variants!`.
+/// The generated tests run every variant of a part on the same inputs and assert that
+/// they agree: on every example and on random inputs of a generator. The comparison on the
+/// real input is ignored by default, as it runs slow variants in a debug build.
+use std::{env, path::Path};
+
+use crate::input_path;
+use crate::template::answers::shorten;
+use crate::template::examples::Example;
+use crate::template::records::{PartRecord, PartStatus};
+use crate::template::{ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET, PuzzleId, read_file_input};
+
+/// Number of random inputs the variants are compared on.
+pub const RANDOM_CASES: usize = 100;
+
+/// Seed of the random inputs, unless `AOC_SEED` is set.
+pub const DEFAULT_SEED: u64 = 0x5eed_2015;
+
+/// The answer of a single variant of a part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariantAnswer {
+    pub part: u8,
+    pub name: &'static str,
+    pub answer: Option<String>,
+}
+
+/// The variants of a day, generated by the `variants!` macro as `VARIANTS`.
+#[derive(Clone, Copy)]
+pub struct Variants {
+    pub id: PuzzleId,
+    /// Runs every variant of every part on the input.
+    pub answers: fn(&str) -> Vec<VariantAnswer>,
+    /// Times every variant of every part on the input, like `cargo time` does for parts.
+    pub bench: fn(&str) -> Vec<PartRecord>,
+    /// Creates random inputs to compare the variants on.
+    pub generator: Option<fn(&mut Rng) -> String>,
+}
+
+impl Variants {
+    /// Stands in for the `VARIANTS` of days that do not call `variants!`.
+    #[must_use]
+    pub const fn none(id: PuzzleId) -> Self {
+        Variants {
+            id,
+            answers: |_| vec![],
+            bench: |_| vec![],
+            generator: None,
+        }
+    }
+}
+
+/// Small xorshift generator for random puzzle inputs, seeded by `AOC_SEED` or a fixed seed.
+pub struct Rng(u64);
+
+impl Rng {
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Rng(seed.max(1))
+    }
+
+    /// Seed of the random inputs. The default seed keeps test runs reproducible,
+    /// `AOC_SEED` compares the variants on other inputs.
+    ///
+    /// # Panics
+    /// Panics if `AOC_SEED` is set to something else than a number.
+    #[must_use]
+    pub fn seed() -> u64 {
+        match env::var("AOC_SEED") {
+            Ok(x) => x
+                .trim()
+                .parse()
+                .unwrap_or_else(|e| panic!("invalid value for AOC_SEED `{x}`: {e}")),
+            Err(_) => DEFAULT_SEED,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number in `min..=max`.
+    pub fn range(&mut self, min: u64, max: u64) -> u64 {
+        min + self.next_u64() % (max - min + 1)
+    }
+
+    /// Picks one of `items`.
+    ///
+    /// # Panics
+    /// Panics if `items` is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        assert!(!items.is_empty(), "can not choose from an empty slice.");
+        &items[self.range(0, items.len() as u64 - 1) as usize]
+    }
+}
+
+/// Describes the first part whose variants disagree, if any.
+#[must_use]
+pub fn disagreement(answers: &[VariantAnswer]) -> Option<String> {
+    let first_disagreement = answers.iter().find(|a| {
+        answers
+            .iter()
+            .any(|b| a.part == b.part && a.answer != b.answer)
+    })?;
+
+    let lines: Vec<String> = answers
+        .iter()
+        .filter(|a| a.part == first_disagreement.part)
+        .map(|a| format!("  {}: {:?}", a.name, a.answer))
+        .collect();
+
+    Some(format!(
+        "variants of part {} disagree:\n{}",
+        first_disagreement.part,
+        lines.join("\n")
+    ))
+}
+
+/// Compares the variants on every example of the day.
+pub fn check_examples(variants: Variants) {
+    for example in Example::read_all(variants.id).unwrap() {
+        let answers = example.with(|input| (variants.answers)(input));
+
+        if let Some(message) = disagreement(&answers) {
+            panic!("{}: {message}", example.name);
+        }
+    }
+}
+
+/// Compares the variants on the real input, if it was downloaded.
+/// The generated test is ignored by default, run it with `cargo test -- --ignored`.
+pub fn check_input(variants: Variants) {
+    if !Path::new(&format!(input_path!(), variants.id.year, variants.id.day)).exists() {
+        return;
+    }
+
+    let input = read_file_input("", variants.id, "input.txt");
+
+    if let Some(message) = disagreement(&(variants.answers)(&input)) {
+        panic!("input.txt: {message}");
+    }
+}
+
+/// Compares the variants on random inputs, if the day has a generator.
+pub fn check_random(variants: Variants) {
+    let Some(generator) = variants.generator else {
+        return;
+    };
+
+    let seed = Rng::seed();
+    let mut rng = Rng::new(seed);
+
+    for _ in 0..RANDOM_CASES {
+        let input = generator(&mut rng);
+
+        if let Some(message) = disagreement(&(variants.answers)(&input)) {
+            panic!("{message}\ninput:\n{input}\nrerun with AOC_SEED={seed}");
+        }
+    }
+}
+
+/// The fastest solved variant of every part, in part order.
+#[must_use]
+pub fn winners(records: &[PartRecord]) -> Vec<&PartRecord> {
+    let mut parts: Vec<u8> = records.iter().map(|r| r.part).collect();
+    parts.dedup();
+
+    parts
+        .into_iter()
+        .filter_map(|part| {
+            records
+                .iter()
+                .filter(|r| r.part == part && r.status == PartStatus::Solved)
+                .min_by_key(|r| r.median)
+        })
+        .collect()
+}
+
+/// How many times faster `record` is than `baseline`.
+fn speedup(baseline: &PartRecord, record: &PartRecord) -> Option<f64> {
+    let median = record.median.as_secs_f64();
+
+    if median == 0.0 {
+        return None;
+    }
+
+    Some(baseline.median.as_secs_f64() / median)
+}
+
+/// Prints the benched variants as a table, with the speedup relative to the first variant of a part.
+/// The fastest variant of every part is highlighted, variants that disagree are called out below.
+pub fn print_report(records: &[PartRecord]) {
+    let winners = winners(records);
+
+    println!(
+        "{ANSI_BOLD}{:<6} {:<28} {:<16} {:>10} {:>10} {:>8}{ANSI_RESET}",
+        "Part", "Variant", "Answer", "Median", "Std dev", "Speedup"
+    );
+
+    for record in records {
+        let baseline = records.iter().find(|r| r.part == record.part).unwrap();
+
+        let speedup = speedup(baseline, record)
+            .map_or_else(|| "-".into(), |speedup| format!("{speedup:.2}x"));
+
+        let line = format!(
+            "{:<6} {:<28} {:<16} {:>10} {:>10} {:>8}",
+            record.part,
+            record.variant.as_deref().unwrap_or("-"),
+            shorten(record.answer.as_deref()),
+            format!("{:.1?}", record.median),
+            format!("{:.1?}", record.std_dev),
+            speedup
+        );
+
+        if winners.contains(&record) {
+            println!("{ANSI_GREEN}{line}{ANSI_RESET}");
+        } else {
+            println!("{line}");
+        }
+    }
+
+    let mut parts: Vec<u8> = records.iter().map(|r| r.part).collect();
+    parts.dedup();
+
+    for part in parts {
+        let mut answers = records.iter().filter(|r| r.part == part).map(|r| &r.answer);
+
+        if let Some(first) = answers.next()
+            && answers.any(|answer| answer != first)
+        {
+            println!("{ANSI_RED}The variants of part {part} disagree on the answer.{ANSI_RESET}");
+        }
+    }
+}
+
+/// Registers alternate implementations of the parts of a day, listed by part number.
+/// Days with a shared `parse` function pass it first, a random input generator can follow last:
+///
+/// ```ignore
+/// advent_of_code::variants!(2 => [part_two, part_two_bruteforce]);
+/// advent_of_code::variants!(parse = parse; 1 => [part_one, part_one_slow]; generator = random_input);
+/// ```
+#[macro_export]
+macro_rules! variants {
+    (parse = $parse:expr; $($part:literal => [$($func:path),+ $(,)?]),+ $(; generator = $generator:expr)? $(;)?) => {
+        $crate::variants!(
+            @impl input [let parsed = $parse(input);] &parsed;
+            $($part => [$($func),+]),+;
+            $($generator)?
+        );
+    };
+
+    ($($part:literal => [$($func:path),+ $(,)?]),+ $(; generator = $generator:expr)? $(;)?) => {
+        $crate::variants!(
+            @impl input [] input;
+            $($part => [$($func),+]),+;
+            $($generator)?
+        );
+    };
+
+    (@generator) => { None };
+    (@generator $generator:expr) => {
+        Some($generator as fn(&mut $crate::template::variants::Rng) -> String)
+    };
+
+    (@impl $input:ident [$($setup:tt)*] $arg:expr; $($part:literal => [$($func:path),+]),+; $($generator:expr)?) => {
+        /// Alternate implementations of this day, compared by the generated `variant_tests`.
+        pub const VARIANTS: $crate::template::variants::Variants = {
+            fn answers($input: &str) -> Vec<$crate::template::variants::VariantAnswer> {
+                $($setup)*
+                let mut answers = vec![];
+                $(
+                    // NOTE: examples for one part are often not valid input for the other one.
+                    if !$crate::template::examples::skips_part($part) {
+                        $(
+                            answers.push($crate::template::variants::VariantAnswer {
+                                part: $part,
+                                name: stringify!($func),
+                                answer: $func($arg).map(|x| x.to_string()),
+                            });
+                        )+
+                    }
+                )+
+                answers
+            }
+
+            fn bench($input: &str) -> Vec<$crate::template::records::PartRecord> {
+                use $crate::template::runner::run_variant;
+                $($setup)*
+                vec![$($( run_variant($func, $arg, PUZZLE, $part, stringify!($func)) ),+),+]
+            }
+
+            $crate::template::variants::Variants {
+                id: PUZZLE,
+                answers,
+                bench,
+                generator: $crate::variants!(@generator $($generator)?),
+            }
+        };
+
+        #[cfg(test)]
+        mod variant_tests {
+            #[test]
+            fn variants_agree_on_examples() {
+                $crate::template::variants::check_examples(super::VARIANTS);
+            }
+
+            #[test]
+            #[ignore = "runs every variant on the real input, see `check_input`"]
+            fn variants_agree_on_input() {
+                $crate::template::variants::check_input(super::VARIANTS);
+            }
+
+            #[test]
+            fn variants_agree_on_random_inputs() {
+                $crate::template::variants::check_random(super::VARIANTS);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Rng, VariantAnswer, disagreement, winners};
+    use crate::{
+        day,
+        template::records::{PartRecord, PartStatus},
+    };
+    use std::time::Duration;
+
+    fn answer(part: u8, name: &'static str, answer: &str) -> VariantAnswer {
+        VariantAnswer {
+            part,
+            name,
+            answer: Some(answer.into()),
+        }
+    }
+
+    #[test]
+    fn accepts_agreeing_variants() {
+        let answers = [
+            answer(1, "part_one", "42"),
+            answer(1, "part_one_slow", "42"),
+            answer(2, "part_two", "7"),
+        ];
+        assert_eq!(disagreement(&answers), None);
+    }
+
+    #[test]
+    fn reports_disagreeing_variants() {
+        let answers = [
+            answer(1, "part_one", "42"),
+            answer(2, "part_two", "7"),
+            answer(2, "part_two_slow", "8"),
+        ];
+        assert_eq!(
+            disagreement(&answers),
+            Some(
+                "variants of part 2 disagree:\n  part_two: Some(\"7\")\n  part_two_slow: Some(\"8\")"
+                    .into()
+            )
+        );
+    }
+
+    fn record(part: u8, name: &str, status: PartStatus, median_ms: u64) -> PartRecord {
+        PartRecord {
+            day: day!(7),
+            part,
+            status,
+            answer: Some("42".into()),
+            samples: 10,
+            outliers: 0,
+            median: Duration::from_millis(median_ms),
+            std_dev: Duration::ZERO,
+            min: Duration::ZERO,
+            max: Duration::ZERO,
+            p95: Duration::ZERO,
+            p99: Duration::ZERO,
+            heap: Default::default(),
+            variant: Some(name.into()),
+        }
+    }
+
+    #[test]
+    fn picks_fastest_solved_variants() {
+        let records = [
+            record(1, "part_one", PartStatus::Solved, 3),
+            record(1, "part_one_fast", PartStatus::Solved, 1),
+            record(2, "part_two", PartStatus::Solved, 5),
+            record(2, "part_two_wip", PartStatus::Unsolved, 0),
+        ];
+
+        let names: Vec<_> = winners(&records)
+            .iter()
+            .map(|r| (r.part, r.variant.as_deref().unwrap()))
+            .collect();
+        assert_eq!(names, vec![(1, "part_one_fast"), (2, "part_two")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty slice")]
+    fn panics_when_choosing_from_nothing() {
+        Rng::new(42).choose::<u8>(&[]);
+    }
+
+    #[test]
+    fn generates_numbers_in_range() {
+        let mut rng = Rng::new(42);
+        for _ in 0..1000 {
+            let x = rng.range(3, 7);
+            assert_eq!((3..=7).contains(&x), true);
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::{Day, all_days};
+
+/// The first year of advent.
+const FIRST_YEAR: u16 = 2015;
+
+/// A valid year of advent (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2025).unwrap();
+/// assert_eq!(year.to_string(), "2025")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if advent took place that year,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The year set in `AOC_YEAR`, the default of every command.
+    /// A value that is not a year of advent is an error.
+    pub fn from_env() -> Result<Option<Self>, String> {
+        env::var("AOC_YEAR").ok().map_or(Ok(None), |x| {
+            x.trim()
+                .parse()
+                .map(Some)
+                .map_err(|e| format!("invalid value for AOC_YEAR `{x}`: {e}"))
+        })
+    }
+
+    /// Reads the year from the name of a solution file like `src/bin/2025_09.rs`.
+    pub const fn from_path(path: &str) -> Option<Self> {
+        let bytes = path.as_bytes();
+
+        let mut start = bytes.len();
+        while start > 0 && bytes[start - 1] != b'/' && bytes[start - 1] != b'\\' {
+            start -= 1;
+        }
+
+        if bytes.len() - start < 5 || bytes[start + 4] != b'_' {
+            return None;
+        }
+
+        let mut year = 0;
+        let mut i = start;
+        while i < start + 4 {
+            if !bytes[i].is_ascii_digit() {
+                return None;
+            }
+            year = year * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        Self::new(year)
+    }
+
+    /// Number of days of advent in this year, advent has 12 days since 2025.
+    pub fn day_count(self) -> u8 {
+        if self.0 >= 2025 { 12 } else { 25 }
+    }
+
+    /// An iterator that yields every day of advent in this year.
+    pub fn days(self) -> impl Iterator<Item = Day> {
+        let count = self.day_count();
+        all_days().take_while(move |day| *day <= count)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of advent, starting with 2015")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A puzzle of a given year, the key of its directory and its binary.
+///
+/// # Display
+/// This value displays as the name of the binary of the puzzle.
+///
+/// ```
+/// # use advent_of_code::template::{Day, PuzzleId, Year};
+/// let id = PuzzleId::new(Year::new(2025).unwrap(), Day::new(9).unwrap());
+/// assert_eq!(id.to_string(), "2025_09")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting a year of advent starting with 2015")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PuzzleId, Year};
+    use crate::day;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2025".parse::<Year>().ok(), Some(Year(2025)));
+        assert_eq!("2014".parse::<Year>().is_err(), true);
+        assert_eq!("25".parse::<Year>().is_err(), true);
+    }
+
+    #[test]
+    fn reads_year_from_path() {
+        assert_eq!(Year::from_path("src/bin/2025_09.rs"), Some(Year(2025)));
+        assert_eq!(Year::from_path("src\\bin\\2016_25.rs"), Some(Year(2016)));
+        assert_eq!(Year::from_path("src/bin/09.rs"), None);
+        assert_eq!(Year::from_path("src/bin/all.rs"), None);
+    }
+
+    #[test]
+    fn counts_days_of_advent() {
+        assert_eq!(Year(2015).days().count(), 25);
+        assert_eq!(Year(2025).days().last(), Some(day!(12)));
+    }
+
+    #[test]
+    fn displays_puzzle_ids() {
+        assert_eq!(PuzzleId::new(Year(2016), day!(1)).to_string(), "2016_01");
+    }
+}